@@ -1,25 +1,46 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
+use std::path::{Path, PathBuf};
 use swc_core::{
     ecma::{
         ast::Program,
         visit::{as_folder, FoldWith},
     },
-    plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
+    plugin::{
+        metadata::TransformPluginMetadataContextKind, plugin_transform,
+        proxies::TransformPluginProgramMetadata,
+    },
 };
-use swc_vue_jsx_visitor::VueJsxTransformVisitor;
+use swc_vue_jsx_visitor::{Options, VueJsxTransformVisitor};
 
 #[plugin_transform]
 pub fn vue_jsx(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
-    let options = metadata
+    let mut options: Options = metadata
         .get_transform_plugin_config()
         .map(|json| {
             serde_json::from_str(&json).expect("failed to parse config of plugin 'vue-jsx'")
         })
         .unwrap_or_default();
-    program.fold_with(&mut as_folder(VueJsxTransformVisitor::new(
-        options,
-        metadata.unresolved_mark,
-        metadata.comments,
-    )))
+    let cwd = metadata.get_context(&TransformPluginMetadataContextKind::Cwd);
+    if let Some(tsconfig) = options.tsconfig.as_mut() {
+        *tsconfig = to_plugin_path(tsconfig, cwd.as_deref())
+            .to_string_lossy()
+            .into_owned();
+    }
+
+    let mut visitor =
+        VueJsxTransformVisitor::new(options, metadata.unresolved_mark, metadata.comments);
+    if let Some(filename) = metadata.get_context(&TransformPluginMetadataContextKind::Filename) {
+        visitor = visitor.with_filename(to_plugin_path(&filename, cwd.as_deref()));
+    }
+    program.fold_with(&mut as_folder(visitor))
+}
+
+/// Files can only be accessed via `/cwd`, where working directory is mounted by SWC.
+fn to_plugin_path(path: &str, cwd: Option<&str>) -> PathBuf {
+    let path = Path::new(path);
+    Path::new("/cwd").join(
+        cwd.and_then(|cwd| path.strip_prefix(cwd).ok())
+            .unwrap_or(path),
+    )
 }
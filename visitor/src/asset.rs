@@ -0,0 +1,119 @@
+use crate::VueJsxTransformVisitor;
+use fnv::FnvHashMap;
+use std::mem;
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{
+        ast::*,
+        atoms::JsWord,
+        utils::{private_ident, quote_str},
+    },
+};
+
+/// Components and directives resolved in a function,
+/// which are declared at the top of its body.
+#[derive(Default)]
+pub(crate) struct AssetScope {
+    decls: Vec<VarDeclarator>,
+    resolved: FnvHashMap<(&'static str, JsWord), Ident>,
+    /// Callbacks like `items.map(item => <Item />)` are called many times in one render,
+    /// so assets are resolved in the enclosing function instead.
+    is_callback: bool,
+}
+
+impl<C> VueJsxTransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Resolve registered component or directive by `resolveComponent` or `resolveDirective`.
+    /// Result is stored in a constant at the top of the enclosing render function,
+    /// so each asset is resolved only once no matter how many times it's used.
+    pub(crate) fn resolve_asset(&mut self, helper: &'static str, name: &str) -> Ident {
+        let key = (helper, JsWord::from(name));
+        if let Some(ident) = self.current_asset_scope().resolved.get(&key) {
+            return ident.clone();
+        }
+
+        let kind = if helper == "resolveDirective" {
+            "directive"
+        } else {
+            "component"
+        };
+        let ident = private_ident!(format!(
+            "_{kind}_{}",
+            name.replace(
+                |c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '$',
+                "_"
+            )
+        ));
+        let init = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Ident(self.import_from_vue(helper)))),
+            args: vec![ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Lit(Lit::Str(quote_str!(name)))),
+            }],
+            type_args: None,
+        });
+
+        let scope = self.current_asset_scope();
+        scope.decls.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(ident.clone().into()),
+            init: Some(Box::new(init)),
+            definite: false,
+        });
+        scope.resolved.insert(key, ident.clone());
+        ident
+    }
+
+    fn current_asset_scope(&mut self) -> &mut AssetScope {
+        match self
+            .asset_scopes
+            .iter()
+            .rposition(|scope| !scope.is_callback)
+        {
+            Some(index) => &mut self.asset_scopes[index],
+            // there're only functions passed as arguments, so the outermost one is used
+            None => match self.asset_scopes.first_mut() {
+                Some(scope) => scope,
+                None => &mut self.module_asset_scope,
+            },
+        }
+    }
+
+    /// Start a new scope of assets when entering a function.
+    pub(crate) fn enter_asset_scope(&mut self) {
+        let is_callback = mem::take(&mut self.visiting_callback);
+        self.asset_scopes.push(AssetScope {
+            is_callback,
+            ..Default::default()
+        });
+    }
+
+    /// Finish the scope of assets when leaving a function,
+    /// returning the declaration of assets resolved in it.
+    pub(crate) fn leave_asset_scope(&mut self) -> Option<Stmt> {
+        self.asset_scopes
+            .pop()
+            .and_then(|scope| build_asset_decl(scope.decls))
+    }
+
+    /// Take the declaration of assets resolved outside functions.
+    pub(crate) fn take_module_assets(&mut self) -> Option<Stmt> {
+        build_asset_decl(mem::take(&mut self.module_asset_scope).decls)
+    }
+}
+
+fn build_asset_decl(decls: Vec<VarDeclarator>) -> Option<Stmt> {
+    if decls.is_empty() {
+        None
+    } else {
+        Some(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Const,
+            declare: false,
+            decls,
+        }))))
+    }
+}
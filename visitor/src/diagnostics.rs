@@ -0,0 +1,240 @@
+use crate::Options;
+use std::borrow::Cow;
+use swc_core::{
+    common::{errors::DiagnosticId, Span},
+    plugin::errors::HANDLER,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Severity {
+    Error,
+    Warning,
+}
+
+/// Problems found in JSX or types.
+///
+/// Each kind has a stable code, so tools can match diagnostics without parsing messages.
+/// Codes must never be reused or renumbered once released.
+pub(crate) enum Diagnostic<'a> {
+    /// `VUEJSX001`
+    VTextWithoutExpr,
+    /// `VUEJSX002`
+    VHtmlWithoutExpr,
+    /// `VUEJSX003`
+    VModelWithoutExpr,
+    /// `VUEJSX004`
+    InvalidVModels,
+    /// `VUEJSX005`
+    InvalidVSlots,
+    /// `VUEJSX006`
+    NonStringVModelType,
+    /// `VUEJSX007`
+    InvalidVFor,
+    /// `VUEJSX008`
+    VIfWithoutExpr,
+    /// `VUEJSX009`
+    OrphanVElse,
+    /// `VUEJSX010`
+    CacheOutsideRender,
+    /// `VUEJSX011`
+    VMemoWithoutExpr,
+    /// `VUEJSX012`
+    UnknownVModelModifier { modifier: &'a str },
+    /// `VUEJSX013`
+    NonAssignableVModel,
+    /// `VUEJSX014`
+    InvalidVModelTarget,
+    /// `VUEJSX101`
+    UnresolvableTypeReference,
+    /// `VUEJSX102`
+    UnresolvableImportedType,
+    /// `VUEJSX103`
+    UnresolvableType,
+    /// `VUEJSX104`
+    UnsupportedIndexKey,
+    /// `VUEJSX105`
+    UnsupportedPropKey,
+    /// `VUEJSX106`
+    FailedToReadTsconfig { path: &'a str, reason: String },
+    /// `VUEJSX107`
+    FailedToLoadTypes { src: &'a str },
+}
+
+impl Diagnostic<'_> {
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Diagnostic::VTextWithoutExpr => "VUEJSX001",
+            Diagnostic::VHtmlWithoutExpr => "VUEJSX002",
+            Diagnostic::VModelWithoutExpr => "VUEJSX003",
+            Diagnostic::InvalidVModels => "VUEJSX004",
+            Diagnostic::InvalidVSlots => "VUEJSX005",
+            Diagnostic::NonStringVModelType => "VUEJSX006",
+            Diagnostic::InvalidVFor => "VUEJSX007",
+            Diagnostic::VIfWithoutExpr => "VUEJSX008",
+            Diagnostic::OrphanVElse => "VUEJSX009",
+            Diagnostic::CacheOutsideRender => "VUEJSX010",
+            Diagnostic::VMemoWithoutExpr => "VUEJSX011",
+            Diagnostic::UnknownVModelModifier { .. } => "VUEJSX012",
+            Diagnostic::NonAssignableVModel => "VUEJSX013",
+            Diagnostic::InvalidVModelTarget => "VUEJSX014",
+            Diagnostic::UnresolvableTypeReference => "VUEJSX101",
+            Diagnostic::UnresolvableImportedType => "VUEJSX102",
+            Diagnostic::UnresolvableType => "VUEJSX103",
+            Diagnostic::UnsupportedIndexKey => "VUEJSX104",
+            Diagnostic::UnsupportedPropKey => "VUEJSX105",
+            Diagnostic::FailedToReadTsconfig { .. } => "VUEJSX106",
+            Diagnostic::FailedToLoadTypes { .. } => "VUEJSX107",
+        }
+    }
+
+    pub(crate) fn severity(&self) -> Severity {
+        match self {
+            // the attribute is ignored, but the rest of the element still works
+            Diagnostic::InvalidVSlots
+            | Diagnostic::NonStringVModelType
+            | Diagnostic::CacheOutsideRender
+            | Diagnostic::NonAssignableVModel => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    fn message(&self) -> Cow<'static, str> {
+        match self {
+            Diagnostic::VTextWithoutExpr => {
+                "You have to use JSX Expression inside your `v-text`.".into()
+            }
+            Diagnostic::VHtmlWithoutExpr => {
+                "You have to use JSX Expression inside your `v-html`.".into()
+            }
+            Diagnostic::VModelWithoutExpr => {
+                "You have to use JSX Expression inside your `v-model`.".into()
+            }
+            Diagnostic::InvalidVModels => {
+                "you should pass a Two-dimensional Arrays to v-models".into()
+            }
+            Diagnostic::InvalidVSlots => {
+                "`v-slots` should be an object or a variable, so it's ignored.".into()
+            }
+            Diagnostic::NonStringVModelType => {
+                "`type` of element with `v-model` should be a string.".into()
+            }
+            Diagnostic::InvalidVFor => "`v-for` should be like `v-for={item in list}`.".into(),
+            Diagnostic::VIfWithoutExpr => {
+                "You have to use JSX Expression inside your `v-if` or `v-else-if`.".into()
+            }
+            Diagnostic::OrphanVElse => {
+                "`v-else-if` or `v-else` must be next to element with `v-if` or `v-else-if`.".into()
+            }
+            Diagnostic::CacheOutsideRender => {
+                "`v-once` and `v-memo` only work in render function returned from `setup`, so they're ignored.".into()
+            }
+            Diagnostic::VMemoWithoutExpr => {
+                "You have to use JSX Expression inside your `v-memo`.".into()
+            }
+            Diagnostic::UnknownVModelModifier { modifier } => {
+                format!("Unknown modifier `{modifier}` of `v-model` on native element.").into()
+            }
+            Diagnostic::NonAssignableVModel => {
+                "Value of `v-model` isn't assignable, so it won't be updated.".into()
+            }
+            Diagnostic::InvalidVModelTarget => {
+                "`v-model` can't be bound to optional chain or function call.".into()
+            }
+            Diagnostic::UnresolvableTypeReference => {
+                "Unresolvable type reference or unsupported built-in utility type.".into()
+            }
+            Diagnostic::UnresolvableImportedType => {
+                "Unresolvable type imported from other module.".into()
+            }
+            Diagnostic::UnresolvableType => "Unresolvable type.".into(),
+            Diagnostic::UnsupportedIndexKey => "Unsupported type as index key.".into(),
+            Diagnostic::UnsupportedPropKey => "Unsupported prop key.".into(),
+            Diagnostic::FailedToReadTsconfig { path, reason } => {
+                format!("Failed to read '{path}': {reason}").into()
+            }
+            Diagnostic::FailedToLoadTypes { src } => {
+                format!("Failed to load types from '{src}'.").into()
+            }
+        }
+    }
+
+    fn help(&self) -> Option<&'static str> {
+        match self {
+            Diagnostic::VTextWithoutExpr => {
+                Some("wrap the value with braces, like `v-text={text}`")
+            }
+            Diagnostic::VHtmlWithoutExpr => {
+                Some("wrap the value with braces, like `v-html={html}`")
+            }
+            Diagnostic::VModelWithoutExpr => {
+                Some("pass the bound variable with braces, like `v-model={value}`")
+            }
+            Diagnostic::InvalidVModels => {
+                Some("pass arrays of `v-model`, like `v-models={[[foo, \"foo\"], [bar, \"bar\"]]}`")
+            }
+            Diagnostic::InvalidVSlots => {
+                Some("pass slots as an object, like `v-slots={{ default: () => <div /> }}`")
+            }
+            Diagnostic::NonStringVModelType => {
+                Some("use a string literal like `type=\"checkbox\"` or an expression of string")
+            }
+            Diagnostic::InvalidVFor => {
+                Some("use `v-for={(item, index) in list}` to access index of item")
+            }
+            Diagnostic::VIfWithoutExpr => Some("pass the condition with braces, like `v-if={ok}`"),
+            Diagnostic::OrphanVElse => {
+                Some("only whitespaces and comments are allowed between elements of the chain")
+            }
+            Diagnostic::CacheOutsideRender => Some(
+                "render cache is stored per component, so define the component by `defineComponent`",
+            ),
+            Diagnostic::VMemoWithoutExpr => {
+                Some("pass dependencies as an array, like `v-memo={[a, b]}`")
+            }
+            Diagnostic::UnknownVModelModifier { .. } => {
+                Some("only `lazy`, `number` and `trim` are supported by native elements")
+            }
+            Diagnostic::NonAssignableVModel => {
+                Some("bind a variable or a property, like `v-model={state.value}`")
+            }
+            Diagnostic::InvalidVModelTarget => Some(
+                "pass a getter and a setter instead, like `v-model={[() => a?.b, (v) => a && (a.b = v)]}`",
+            ),
+            Diagnostic::UnresolvableTypeReference => {
+                Some("declare the type in this file, or import it from a TypeScript module")
+            }
+            Diagnostic::UnresolvableImportedType => {
+                Some("make sure the type is exported from a `.ts` or `.d.ts` module")
+            }
+            Diagnostic::UnresolvableType | Diagnostic::UnsupportedPropKey => None,
+            Diagnostic::UnsupportedIndexKey => {
+                Some("use a string literal or a union of string literals as index key")
+            }
+            Diagnostic::FailedToReadTsconfig { .. } => {
+                Some("check whether the `tsconfig` option points to a valid JSON file")
+            }
+            Diagnostic::FailedToLoadTypes { .. } => {
+                Some("only `.ts` and `.d.ts` files can be loaded, check the path or `tsconfig`")
+            }
+        }
+    }
+
+    /// Report this diagnostic.
+    /// Warnings are reported as errors if `warningsAsErrors` option is enabled.
+    pub(crate) fn emit(&self, span: Span, options: &Options) {
+        let message = self.message();
+        let code = self.code().into();
+        HANDLER.with(|handler| {
+            let mut builder = if self.severity() == Severity::Warning && !options.warnings_as_errors
+            {
+                handler.struct_span_warn_with_code(span, &message, DiagnosticId::Lint(code))
+            } else {
+                handler.struct_span_err_with_code(span, &message, DiagnosticId::Error(code))
+            };
+            if let Some(help) = self.help() {
+                builder.help(help);
+            }
+            builder.emit();
+        });
+    }
+}
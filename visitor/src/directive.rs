@@ -1,14 +1,17 @@
-use std::{collections::BTreeSet, str::Split};
+use crate::{diagnostics::Diagnostic, util, Options};
+use std::{collections::BTreeSet, mem};
 use swc_core::{
-    common::DUMMY_SP,
+    common::{Span, Spanned, DUMMY_SP},
     ecma::{
         ast::*,
         atoms::JsWord,
         utils::{quote_ident, quote_str},
     },
-    plugin::errors::HANDLER,
 };
 
+/// Modifiers supported by `v-model` on native elements.
+const V_MODEL_MODIFIERS: [&str; 3] = ["lazy", "number", "trim"];
+
 pub(crate) fn is_directive(jsx_attr: &JSXAttr) -> bool {
     let name = match &jsx_attr.name {
         JSXAttrName::Ident(ident) => &ident.sym,
@@ -18,6 +21,7 @@ pub(crate) fn is_directive(jsx_attr: &JSXAttr) -> bool {
 }
 
 pub(crate) struct NormalDirective {
+    pub(crate) span: Span,
     pub(crate) name: JsWord,
     pub(crate) argument: Option<Expr>,
     pub(crate) modifiers: Option<Expr>,
@@ -25,10 +29,13 @@ pub(crate) struct NormalDirective {
 }
 
 pub(crate) struct VModelDirective {
+    pub(crate) span: Span,
     pub(crate) argument: Option<Expr>,
     pub(crate) transformed_argument: Option<Expr>,
     pub(crate) modifiers: Option<Expr>,
     pub(crate) value: Expr,
+    /// Handler of `onUpdate:modelValue` event or other model events.
+    pub(crate) updater: Expr,
 }
 
 pub(crate) enum Directive {
@@ -39,8 +46,12 @@ pub(crate) enum Directive {
     Slots(Option<Box<Expr>>),
 }
 
-pub(crate) fn parse_directive(jsx_attr: &JSXAttr, is_component: bool) -> Directive {
-    let (name, argument, splitted) = match &jsx_attr.name {
+pub(crate) fn parse_directive(
+    jsx_attr: &JSXAttr,
+    is_component: bool,
+    options: &Options,
+) -> Directive {
+    let (name, raw_argument, splitted) = match &jsx_attr.name {
         JSXAttrName::Ident(ident) => {
             let mut splitted = ident
                 .sym
@@ -66,13 +77,21 @@ pub(crate) fn parse_directive(jsx_attr: &JSXAttr, is_component: bool) -> Directi
         }
     };
 
-    let mut argument = argument.map(|argument| Expr::Lit(Lit::Str(quote_str!(argument))));
+    let mut argument = raw_argument.map(|argument| Expr::Lit(Lit::Str(quote_str!(argument))));
 
     match &*name {
-        "html" => return parse_v_html_directive(jsx_attr),
-        "text" => return parse_v_text_directive(jsx_attr),
-        "model" => return parse_v_model_directive(jsx_attr, is_component, argument, splitted),
-        "slots" => return parse_v_slots_directive(jsx_attr),
+        "html" => return parse_v_html_directive(jsx_attr, options),
+        "text" => return parse_v_text_directive(jsx_attr, options),
+        // native elements don't accept arguments,
+        // so `v-model_trim` means modifier like `v-model.trim` of Vue templates
+        "model" if !is_component && matches!(jsx_attr.name, JSXAttrName::Ident(..)) => {
+            let modifiers = raw_argument.into_iter().chain(splitted);
+            return parse_v_model_directive(jsx_attr, is_component, None, modifiers, options);
+        }
+        "model" => {
+            return parse_v_model_directive(jsx_attr, is_component, argument, splitted, options)
+        }
+        "slots" => return parse_v_slots_directive(jsx_attr, options),
         _ => {}
     }
 
@@ -85,7 +104,7 @@ pub(crate) fn parse_directive(jsx_attr: &JSXAttr, is_component: bool) -> Directi
     })) = &jsx_attr.value
     {
         if let Expr::Array(ArrayLit { elems, .. }) = &**expr {
-            value = match elems.get(0) {
+            value = match elems.first() {
                 Some(Some(ExprOrSpread { spread: None, expr })) => (**expr).clone(),
                 _ => Expr::Ident(quote_ident!("")),
             };
@@ -118,6 +137,7 @@ pub(crate) fn parse_directive(jsx_attr: &JSXAttr, is_component: bool) -> Directi
     }
 
     Directive::Normal(NormalDirective {
+        span: jsx_attr.span,
         name: JsWord::from(name),
         argument: if modifiers
             .as_ref()
@@ -138,7 +158,8 @@ pub(crate) fn parse_directive(jsx_attr: &JSXAttr, is_component: bool) -> Directi
         } else {
             argument
         },
-        modifiers: modifiers.and_then(|modifiers| transform_modifiers(modifiers, false)),
+        modifiers: modifiers
+            .and_then(|modifiers| transform_modifiers(modifiers, false, jsx_attr.span)),
         value,
     })
 }
@@ -156,7 +177,7 @@ fn parse_modifiers(exprs: &[Option<ExprOrSpread>]) -> BTreeSet<JsWord> {
         .collect()
 }
 
-fn parse_v_text_directive(jsx_attr: &JSXAttr) -> Directive {
+fn parse_v_text_directive(jsx_attr: &JSXAttr, options: &Options) -> Directive {
     let expr = match &jsx_attr.value {
         Some(JSXAttrValue::Lit(lit)) => Expr::Lit(lit.clone()),
         Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
@@ -164,7 +185,7 @@ fn parse_v_text_directive(jsx_attr: &JSXAttr) -> Directive {
             ..
         })) => {
             if let Some(Some(ExprOrSpread { spread: None, expr })) =
-                expr.as_array().and_then(|array| array.elems.get(0))
+                expr.as_array().and_then(|array| array.elems.first())
             {
                 (**expr).clone()
             } else {
@@ -172,12 +193,7 @@ fn parse_v_text_directive(jsx_attr: &JSXAttr) -> Directive {
             }
         }
         None => {
-            HANDLER.with(|handler| {
-                handler.span_err(
-                    jsx_attr.span,
-                    "You have to use JSX Expression inside your `v-text`.",
-                );
-            });
+            Diagnostic::VTextWithoutExpr.emit(jsx_attr.span, options);
             Expr::Lit(Lit::Bool(Bool {
                 span: DUMMY_SP,
                 value: true,
@@ -189,7 +205,7 @@ fn parse_v_text_directive(jsx_attr: &JSXAttr) -> Directive {
     Directive::Text(expr)
 }
 
-fn parse_v_html_directive(jsx_attr: &JSXAttr) -> Directive {
+fn parse_v_html_directive(jsx_attr: &JSXAttr, options: &Options) -> Directive {
     let expr = match &jsx_attr.value {
         Some(JSXAttrValue::Lit(lit)) => Expr::Lit(lit.clone()),
         Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
@@ -197,7 +213,7 @@ fn parse_v_html_directive(jsx_attr: &JSXAttr) -> Directive {
             ..
         })) => {
             if let Some(Some(ExprOrSpread { spread: None, expr })) =
-                expr.as_array().and_then(|array| array.elems.get(0))
+                expr.as_array().and_then(|array| array.elems.first())
             {
                 (**expr).clone()
             } else {
@@ -205,12 +221,7 @@ fn parse_v_html_directive(jsx_attr: &JSXAttr) -> Directive {
             }
         }
         None => {
-            HANDLER.with(|handler| {
-                handler.span_err(
-                    jsx_attr.span,
-                    "You have to use JSX Expression inside your `v-html`.",
-                );
-            });
+            Diagnostic::VHtmlWithoutExpr.emit(jsx_attr.span, options);
             Expr::Lit(Lit::Bool(Bool {
                 span: DUMMY_SP,
                 value: true,
@@ -222,33 +233,30 @@ fn parse_v_html_directive(jsx_attr: &JSXAttr) -> Directive {
     Directive::Html(expr)
 }
 
-fn parse_v_model_directive(
+fn parse_v_model_directive<'a>(
     jsx_attr: &JSXAttr,
     is_component: bool,
     mut argument: Option<Expr>,
-    splitted_attr_name: Split<char>,
+    splitted_attr_name: impl Iterator<Item = &'a str>,
+    options: &Options,
 ) -> Directive {
-    let attr_value = match &jsx_attr.value {
+    let mut attr_value = match &jsx_attr.value {
         Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
             expr: JSXExpr::Expr(expr),
             ..
         })) => (**expr).clone(),
         _ => {
-            HANDLER.with(|handler| {
-                handler.span_err(
-                    jsx_attr.span,
-                    "You have to use JSX Expression inside your `v-model`.",
-                );
-            });
+            Diagnostic::VModelWithoutExpr.emit(jsx_attr.span, options);
             Expr::Ident(quote_ident!(""))
         }
     };
+    let setter = take_v_model_setter(&mut attr_value);
 
     let mut modifiers = None;
     let value;
 
     if let Expr::Array(ArrayLit { elems, .. }) = attr_value {
-        value = match elems.get(0) {
+        value = match elems.first() {
             Some(Some(ExprOrSpread { spread: None, expr })) => (**expr).clone(),
             _ => Expr::Ident(quote_ident!("")),
         };
@@ -282,7 +290,26 @@ fn parse_v_model_directive(
         value = attr_value.clone();
     }
 
+    // modifiers of components are passed as props, so they can be anything
+    if let Some(modifiers) = modifiers.as_mut().filter(|_| !is_component) {
+        modifiers.retain(|modifier| {
+            let is_known = V_MODEL_MODIFIERS.contains(&&**modifier);
+            if !is_known {
+                Diagnostic::UnknownVModelModifier { modifier }.emit(jsx_attr.span, options);
+            }
+            is_known
+        });
+    }
+    let updater = match setter {
+        Some(setter) => setter,
+        None => {
+            check_v_model_target(&value, options);
+            build_v_model_updater(&value, jsx_attr.span)
+        }
+    };
+
     Directive::VModel(VModelDirective {
+        span: jsx_attr.span,
         argument: argument.clone(),
         transformed_argument: if !is_component
             && modifiers
@@ -304,17 +331,107 @@ fn parse_v_model_directive(
         } else {
             argument
         },
-        modifiers: modifiers.and_then(|modifiers| transform_modifiers(modifiers, is_component)),
+        modifiers: modifiers
+            .and_then(|modifiers| transform_modifiers(modifiers, is_component, jsx_attr.span)),
         value,
+        updater,
     })
 }
 
-fn transform_modifiers(modifiers: BTreeSet<JsWord>, quote_prop: bool) -> Option<Expr> {
+/// Take the setter from `v-model={[getter, setter]}`,
+/// then the getter is replaced with the value it returns.
+fn take_v_model_setter(attr_value: &mut Expr) -> Option<Expr> {
+    let Expr::Array(ArrayLit { elems, .. }) = attr_value else {
+        return None;
+    };
+    let is_getter_and_setter = elems.len() >= 2
+        && elems[..2].iter().all(|elem| {
+            matches!(
+                elem,
+                Some(ExprOrSpread { spread: None, expr }) if matches!(&**expr, Expr::Arrow(..) | Expr::Fn(..))
+            )
+        });
+    if !is_getter_and_setter {
+        return None;
+    }
+
+    let setter = elems.remove(1).map(|setter| *setter.expr);
+    if let Some(Some(ExprOrSpread { expr, .. })) = elems.first_mut() {
+        let getter = mem::replace(&mut **expr, Expr::Invalid(Invalid { span: DUMMY_SP }));
+        **expr = match getter {
+            Expr::Arrow(ArrowExpr { params, body, .. }) if params.is_empty() && body.is_expr() => {
+                body.expect_expr().unwrap_parens().clone()
+            }
+            getter => Expr::Call(CallExpr {
+                span: getter.span(),
+                callee: Callee::Expr(Box::new(getter)),
+                args: vec![],
+                type_args: None,
+            }),
+        };
+    }
+    setter
+}
+
+/// Report the value of `v-model` which can't be updated by assignment.
+fn check_v_model_target(value: &Expr, options: &Options) {
+    if util::is_assignable(value) {
+        return;
+    }
+    let mut target = value;
+    while let Expr::Paren(ParenExpr { expr, .. })
+    | Expr::TsAs(TsAsExpr { expr, .. })
+    | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+    | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+    | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) = target
+    {
+        target = expr;
+    }
+    if matches!(target, Expr::OptChain(..) | Expr::Call(..) | Expr::New(..)) {
+        Diagnostic::InvalidVModelTarget.emit(value.span(), options);
+    } else {
+        Diagnostic::NonAssignableVModel.emit(value.span(), options);
+    }
+}
+
+/// Build handler like `$event => value = $event` for model event.
+fn build_v_model_updater(value: &Expr, span: Span) -> Expr {
+    Expr::Arrow(ArrowExpr {
+        span,
+        params: vec![Pat::Ident(BindingIdent {
+            id: quote_ident!("$event"),
+            type_ann: None,
+        })],
+        // non-assignable value has been reported, and it's left as is
+        body: Box::new(if util::is_assignable(value) {
+            BlockStmtOrExpr::Expr(Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: AssignTarget::Simple(SimpleAssignTarget::Paren(ParenExpr {
+                    span: DUMMY_SP,
+                    expr: Box::new(value.clone()),
+                })),
+                right: Box::new(Expr::Ident(quote_ident!("$event"))),
+            })))
+        } else {
+            BlockStmtOrExpr::BlockStmt(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![],
+            })
+        }),
+        is_async: false,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+    })
+}
+
+fn transform_modifiers(modifiers: BTreeSet<JsWord>, quote_prop: bool, span: Span) -> Option<Expr> {
     if modifiers.is_empty() {
         None
     } else {
         Some(Expr::Object(ObjectLit {
-            span: DUMMY_SP,
+            span,
             props: modifiers
                 .into_iter()
                 .map(|modifier| {
@@ -335,7 +452,7 @@ fn transform_modifiers(modifiers: BTreeSet<JsWord>, quote_prop: bool) -> Option<
     }
 }
 
-fn parse_v_slots_directive(jsx_attr: &JSXAttr) -> Directive {
+fn parse_v_slots_directive(jsx_attr: &JSXAttr, options: &Options) -> Directive {
     let expr = match &jsx_attr.value {
         Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
             expr: JSXExpr::Expr(expr),
@@ -346,5 +463,8 @@ fn parse_v_slots_directive(jsx_attr: &JSXAttr) -> Directive {
         },
         _ => None,
     };
+    if expr.is_none() {
+        Diagnostic::InvalidVSlots.emit(jsx_attr.span, options);
+    }
     Directive::Slots(expr)
 }
@@ -0,0 +1,62 @@
+use crate::VueJsxTransformVisitor;
+use swc_core::{
+    common::{comments::Comments, Spanned},
+    ecma::ast::*,
+};
+
+impl<C> VueJsxTransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Remove `is` attribute from `<component is={...}>`,
+    /// then return the element without it and the value of `is`.
+    pub(crate) fn strip_dynamic_component_is(
+        &self,
+        jsx_element: &JSXElement,
+    ) -> Option<(JSXElement, Box<Expr>)> {
+        // `component` declared by user is rendered as usual
+        match &jsx_element.opening.name {
+            JSXElementName::Ident(ident)
+                if ident.sym == "component" && ident.to_id().1.has_mark(self.unresolved_mark) => {}
+            _ => return None,
+        }
+        let (index, value) = jsx_element.opening.attrs.iter().enumerate().find_map(
+            |(index, jsx_attr_or_spread)| match jsx_attr_or_spread {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(ident),
+                    value: Some(value),
+                    ..
+                }) if ident.sym == "is" => Some((index, value)),
+                _ => None,
+            },
+        )?;
+        let value = match value {
+            JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => expr.clone(),
+            JSXAttrValue::Lit(lit) => Box::new(Expr::Lit(lit.clone())),
+            _ => return None,
+        };
+
+        let mut jsx_element = jsx_element.clone();
+        jsx_element.opening.attrs.remove(index);
+        Some((jsx_element, value))
+    }
+
+    /// Build `resolveDynamicComponent(is)`, which resolves registered component by name,
+    /// or returns component and native tag as is.
+    pub(crate) fn build_dynamic_component_tag(&mut self, is: Box<Expr>) -> Expr {
+        Expr::Call(CallExpr {
+            span: is.span(),
+            callee: Callee::Expr(Box::new(Expr::Ident(
+                self.import_from_vue("resolveDynamicComponent"),
+            ))),
+            args: vec![ExprOrSpread {
+                spread: None,
+                expr: is,
+            }],
+            type_args: None,
+        })
+    }
+}
@@ -0,0 +1,260 @@
+use crate::VueJsxTransformVisitor;
+use fnv::FnvHasher;
+use std::{env, hash::Hasher, path::Path};
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{
+        ast::*,
+        atoms::JsWord,
+        utils::{private_ident, quote_ident, quote_str},
+    },
+};
+
+struct HotComponent {
+    local: Ident,
+    exported: JsWord,
+    id: String,
+}
+
+impl<C> VueJsxTransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Register exported components to HMR runtime of Vue and accept updates of this module.
+    pub(crate) fn inject_hmr_code(&self, module: &mut Module) {
+        let Some(filename) = &self.filename else {
+            return;
+        };
+        // keep ID same across machines if possible
+        let filename = env::current_dir()
+            .ok()
+            .and_then(|cwd| filename.strip_prefix(cwd).ok())
+            .unwrap_or(filename);
+
+        let mut components = vec![];
+        let mut locals = vec![];
+        let mut default_local = None;
+        module.body.iter_mut().for_each(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var_decl),
+                ..
+            })) => {
+                components.extend(
+                    self.find_define_component_vars(var_decl)
+                        .map(|ident| (ident.clone(), ident.sym.clone())),
+                );
+            }
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                locals.extend(self.find_define_component_vars(var_decl).cloned());
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                ..
+            })) => {
+                if matches!(&**expr, Expr::Call(call) if self.is_define_component_call(call)) {
+                    let local = private_ident!("__default__");
+                    *item = ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                        span: DUMMY_SP,
+                        kind: VarDeclKind::Const,
+                        declare: false,
+                        decls: vec![VarDeclarator {
+                            span: DUMMY_SP,
+                            name: Pat::Ident(local.clone().into()),
+                            init: Some(expr.clone()),
+                            definite: false,
+                        }],
+                    }))));
+                    components.push((local.clone(), "default".into()));
+                    default_local = Some(local);
+                }
+            }
+            _ => {}
+        });
+        module.body.iter().for_each(|item| {
+            let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                specifiers,
+                src: None,
+                ..
+            })) = item
+            else {
+                return;
+            };
+            specifiers.iter().for_each(|specifier| {
+                if let ExportSpecifier::Named(ExportNamedSpecifier {
+                    orig: ModuleExportName::Ident(orig),
+                    exported,
+                    ..
+                }) = specifier
+                {
+                    // a component exported with multiple names is only registered once
+                    let is_registered = components
+                        .iter()
+                        .any(|(local, _): &(Ident, _)| local.to_id() == orig.to_id());
+                    if is_registered {
+                        return;
+                    }
+                    if let Some(local) = locals.iter().find(|local| local.to_id() == orig.to_id()) {
+                        let exported = match exported {
+                            Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+                            // can't be used as name of parameter in callback of `accept`
+                            Some(ModuleExportName::Str(..)) => return,
+                            None => orig.sym.clone(),
+                        };
+                        components.push((local.clone(), exported));
+                    }
+                }
+            });
+        });
+        if components.is_empty() {
+            return;
+        }
+        if let Some(local) = default_local {
+            module
+                .body
+                .push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
+                    ExportDefaultExpr {
+                        span: DUMMY_SP,
+                        expr: Box::new(Expr::Ident(local)),
+                    },
+                )));
+        }
+
+        let components = components
+            .into_iter()
+            .map(|(local, exported)| HotComponent {
+                id: hash_id(filename, &exported),
+                local,
+                exported,
+            })
+            .collect::<Vec<_>>();
+
+        components.iter().for_each(|component| {
+            module.body.push(build_expr_stmt(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+                    span: DUMMY_SP,
+                    obj: Box::new(Expr::Ident(component.local.clone())),
+                    prop: MemberProp::Ident(quote_ident!("__hmrId")),
+                })),
+                right: Box::new(Expr::Lit(Lit::Str(quote_str!(&*component.id)))),
+            })));
+            module.body.push(build_expr_stmt(build_hmr_runtime_call(
+                "createRecord",
+                &component.id,
+                Expr::Ident(component.local.clone()),
+            )));
+        });
+
+        let (props, stmts) = components
+            .iter()
+            .map(|component| {
+                let param = private_ident!(format!("__{}", component.exported));
+                (
+                    ObjectPatProp::KeyValue(KeyValuePatProp {
+                        key: PropName::Ident(quote_ident!(component.exported.clone())),
+                        value: Box::new(Pat::Ident(param.clone().into())),
+                    }),
+                    Stmt::Expr(ExprStmt {
+                        span: DUMMY_SP,
+                        expr: Box::new(build_hmr_runtime_call(
+                            "reload",
+                            &component.id,
+                            Expr::Ident(param),
+                        )),
+                    }),
+                )
+            })
+            .unzip();
+        module.body.push(build_expr_stmt(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                span: DUMMY_SP,
+                obj: Box::new(Expr::Member(MemberExpr {
+                    span: DUMMY_SP,
+                    obj: Box::new(Expr::MetaProp(MetaPropExpr {
+                        span: DUMMY_SP,
+                        kind: MetaPropKind::ImportMeta,
+                    })),
+                    prop: MemberProp::Ident(quote_ident!("hot")),
+                })),
+                prop: MemberProp::Ident(quote_ident!("accept")),
+            }))),
+            args: vec![ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Arrow(ArrowExpr {
+                    span: DUMMY_SP,
+                    params: vec![Pat::Object(ObjectPat {
+                        span: DUMMY_SP,
+                        props,
+                        optional: false,
+                        type_ann: None,
+                    })],
+                    body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts,
+                    })),
+                    is_async: false,
+                    is_generator: false,
+                    type_params: None,
+                    return_type: None,
+                })),
+            }],
+            type_args: None,
+        })));
+    }
+
+    fn find_define_component_vars<'a>(
+        &'a self,
+        var_decl: &'a VarDecl,
+    ) -> impl Iterator<Item = &'a Ident> + 'a {
+        var_decl
+            .decls
+            .iter()
+            .filter_map(|decl| match (&decl.name, decl.init.as_deref()) {
+                (Pat::Ident(BindingIdent { id, .. }), Some(Expr::Call(call)))
+                    if self.is_define_component_call(call) =>
+                {
+                    Some(id)
+                }
+                _ => None,
+            })
+    }
+}
+
+/// Generate ID from file path and export name, which is stable across builds.
+fn hash_id(filename: &Path, exported: &str) -> String {
+    let mut hasher = FnvHasher::default();
+    hasher.write(filename.to_string_lossy().as_bytes());
+    hasher.write(exported.as_bytes());
+    format!("{:08x}", hasher.finish() as u32)
+}
+
+fn build_hmr_runtime_call(method: &str, id: &str, component: Expr) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(Expr::Ident(quote_ident!("__VUE_HMR_RUNTIME__"))),
+            prop: MemberProp::Ident(quote_ident!(method)),
+        }))),
+        args: vec![
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Lit(Lit::Str(quote_str!(id)))),
+            },
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(component),
+            },
+        ],
+        type_args: None,
+    })
+}
+
+fn build_expr_stmt(expr: Expr) -> ModuleItem {
+    ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(expr),
+    }))
+}
@@ -1,10 +1,14 @@
+use asset::AssetScope;
+use diagnostics::Diagnostic;
 use directive::{is_directive, parse_directive, Directive, NormalDirective};
 use fnv::FnvHashMap;
 use indexmap::IndexSet;
-pub use options::{Options, Regex};
+pub use options::{CustomElements, Glob, Options, Regex};
 use patch_flags::PatchFlags;
+use render_cache::RenderCache;
+use resolve_type::TypeImport;
 use slot_flag::SlotFlag;
-use std::{borrow::Cow, collections::BTreeMap, mem};
+use std::{borrow::Cow, collections::BTreeMap, mem, path::PathBuf, rc::Rc};
 use swc_core::{
     common::{comments::Comments, Mark, Span, Spanned, SyntaxContext, DUMMY_SP},
     ecma::{
@@ -13,18 +17,50 @@ use swc_core::{
         utils::{private_ident, quote_ident, quote_str},
         visit::{VisitMut, VisitMutWith},
     },
-    plugin::errors::HANDLER,
 };
+pub use type_resolver::{FsTypeResolver, TypeResolver};
+use v_if::{ChildOrVIfChain, Condition};
 
+mod asset;
+mod diagnostics;
 mod directive;
+mod dynamic_component;
+mod hmr;
 mod options;
 mod patch_flags;
+mod render_cache;
 mod resolve_type;
+mod script;
 mod slot_flag;
+mod ssr;
+mod type_resolver;
 mod util;
+mod v_for;
+mod v_if;
 
 const FRAGMENT: &str = "Fragment";
 const KEEP_ALIVE: &str = "KeepAlive";
+const TELEPORT: &str = "Teleport";
+/// Components provided by runtime, which are imported automatically if not declared.
+const BUILT_IN_COMPONENTS: [&str; 5] = [
+    KEEP_ALIVE,
+    TELEPORT,
+    "Suspense",
+    "Transition",
+    "TransitionGroup",
+];
+
+/// How a JSX root is opened as a block when `optimize` is enabled.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BlockType {
+    /// Not a root, or blocks aren't generated.
+    None,
+    /// Structure of the whole subtree never changes,
+    /// so dynamic descendants can be tracked by the block.
+    Stable,
+    /// Subtree contains expressions which may change its structure.
+    Unstable,
+}
 
 struct AttrsTransformationResult<'a> {
     attrs: Expr,
@@ -39,11 +75,17 @@ where
 {
     options: Options,
     vue_imports: BTreeMap<&'static str, Ident>,
+    user_vue_imports: FnvHashMap<JsWord, Ident>,
+    server_renderer_imports: BTreeMap<&'static str, Ident>,
     transform_on_helper: Option<Ident>,
 
     define_component: Option<SyntaxContext>,
     interfaces: FnvHashMap<(JsWord, SyntaxContext), TsInterfaceDecl>,
     type_aliases: FnvHashMap<(JsWord, SyntaxContext), TsType>,
+    type_imports: FnvHashMap<(JsWord, SyntaxContext), TypeImport>,
+    filename: Option<PathBuf>,
+    type_resolver: Option<Box<dyn TypeResolver>>,
+    type_modules: FnvHashMap<PathBuf, Rc<Module>>,
 
     unresolved_mark: Mark,
     comments: Option<C>,
@@ -56,6 +98,16 @@ where
 
     assignment_left: Option<Ident>,
     injecting_consts: Vec<VarDeclarator>,
+
+    asset_scopes: Vec<AssetScope>,
+    module_asset_scope: AssetScope,
+    visiting_callback: bool,
+
+    hoisted_consts: Vec<VarDeclarator>,
+    hoisting: bool,
+
+    render_cache: Option<RenderCache>,
+    render_cache_idents: Vec<Ident>,
 }
 
 impl<C> VueJsxTransformVisitor<C>
@@ -66,11 +118,17 @@ where
         Self {
             options,
             vue_imports: Default::default(),
+            user_vue_imports: Default::default(),
+            server_renderer_imports: Default::default(),
             transform_on_helper: None,
 
             define_component: None,
             interfaces: Default::default(),
             type_aliases: Default::default(),
+            type_imports: Default::default(),
+            filename: None,
+            type_resolver: None,
+            type_modules: Default::default(),
 
             unresolved_mark,
             comments,
@@ -83,11 +141,96 @@ where
 
             assignment_left: None,
             injecting_consts: Default::default(),
+
+            asset_scopes: Default::default(),
+            module_asset_scope: Default::default(),
+            visiting_callback: false,
+
+            hoisted_consts: Default::default(),
+            hoisting: false,
+
+            render_cache: None,
+            render_cache_idents: Default::default(),
         }
     }
 
+    /// Set path of the file being transformed,
+    /// which is required for resolving types imported from other modules.
+    pub fn with_filename(mut self, filename: impl Into<PathBuf>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Replace the default resolver which reads imported types from disk.
+    pub fn with_type_resolver(mut self, type_resolver: impl TypeResolver + 'static) -> Self {
+        self.type_resolver = Some(Box::new(type_resolver));
+        self
+    }
+
+    /// Get local binding of the runtime helper.
+    /// Binding imported by user will be reused, otherwise a new one will be generated.
     fn import_from_vue(&mut self, item: &'static str) -> Ident {
         self.vue_imports
+            .entry(item)
+            .or_insert_with_key(|name| {
+                self.user_vue_imports
+                    .get(&JsWord::from(*name))
+                    .cloned()
+                    .unwrap_or_else(|| private_ident!(format!("_{name}")))
+            })
+            .clone()
+    }
+
+    /// Get runtime helpers which aren't imported by user yet.
+    fn collect_vue_imports(&self) -> Vec<(&'static str, Ident)> {
+        self.vue_imports
+            .iter()
+            .filter(|(imported, local)| {
+                self.user_vue_imports
+                    .get(&JsWord::from(**imported))
+                    .is_none_or(|ident| ident.to_id() != local.to_id())
+            })
+            .map(|(imported, local)| (*imported, local.clone()))
+            .collect()
+    }
+
+    fn collect_user_vue_imports(&mut self, module: &Module) {
+        let specifiers = module
+            .body
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl))
+                    if !import_decl.type_only
+                        && import_decl.src.value == *self.options.import_source =>
+                {
+                    Some(&import_decl.specifiers)
+                }
+                _ => None,
+            })
+            .flatten();
+        for specifier in specifiers {
+            let ImportSpecifier::Named(ImportNamedSpecifier {
+                local,
+                imported,
+                is_type_only: false,
+                ..
+            }) = specifier
+            else {
+                continue;
+            };
+            let imported = match imported {
+                Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+                Some(ModuleExportName::Str(str)) => str.value.clone(),
+                None => local.sym.clone(),
+            };
+            self.user_vue_imports
+                .entry(imported)
+                .or_insert_with(|| local.clone());
+        }
+    }
+
+    fn import_from_server_renderer(&mut self, item: &'static str) -> Ident {
+        self.server_renderer_imports
             .entry(item)
             .or_insert_with_key(|name| private_ident!(format!("_{name}")))
             .clone()
@@ -99,23 +242,87 @@ where
             .clone()
     }
 
-    fn transform_jsx_element(&mut self, jsx_element: &JSXElement) -> Expr {
+    fn transform_jsx_element(&mut self, jsx_element: &JSXElement, block_type: BlockType) -> Expr {
+        // `v-if` has higher priority than `v-for`
+        match v_if::find_condition(jsx_element) {
+            Some((_, Condition::If)) => return self.transform_v_if_chain(&[jsx_element], &mut 0),
+            Some((index, _)) => {
+                let mut jsx_element = jsx_element.clone();
+                let jsx_attr = jsx_element.opening.attrs.remove(index);
+                Diagnostic::OrphanVElse.emit(jsx_attr.span(), &self.options);
+                return self.transform_jsx_element(&jsx_element, block_type);
+            }
+            None => {}
+        }
+        // `v-once` caches the whole list, while `v-memo` is checked by each item
+        if let Some(index) = render_cache::find_v_once(jsx_element) {
+            return self.transform_v_once(jsx_element, index, block_type, None);
+        }
+        if let Some(index) = v_for::find_v_for(jsx_element) {
+            return self.transform_v_for(jsx_element, index, None);
+        }
+        if render_cache::find_v_memo(jsx_element).is_some() {
+            return self.transform_v_memo(jsx_element, block_type);
+        }
+
         if self.options.optimize {
             self.slot_flag_stack.push(SlotFlag::Stable);
         }
 
+        // only the root of a static subtree is hoisted,
+        // its descendants are created inside the hoisted vnode
+        let hoist = self.options.optimize
+            && !self.hoisting
+            && self.pragma.is_none()
+            && self.options.pragma.is_none()
+            && self.is_static_element(jsx_element);
+        if hoist {
+            self.hoisting = true;
+        }
+
+        let stripped_element;
+        let (jsx_element, dynamic_component_is) = match self.strip_dynamic_component_is(jsx_element)
+        {
+            Some((element, is)) => {
+                stripped_element = element;
+                (&stripped_element, Some(is))
+            }
+            None => (jsx_element, None),
+        };
+
         let is_component = self.is_component(&jsx_element.opening.name);
         let mut directives = vec![];
         let AttrsTransformationResult {
             attrs,
-            patch_flags,
-            dynamic_props,
+            mut patch_flags,
+            mut dynamic_props,
             slots,
-        } = self.transform_attrs(&jsx_element.opening.attrs, is_component, &mut directives);
+        } = self.transform_attrs(&jsx_element.opening, is_component, &mut directives);
+        let tag = if let Some(is) = dynamic_component_is {
+            // props can't be diffed partially, since the type may change
+            patch_flags = if matches!(attrs, Expr::Lit(Lit::Null(..))) {
+                PatchFlags::empty()
+            } else {
+                PatchFlags::FULL_PROPS
+            };
+            dynamic_props = None;
+            self.build_dynamic_component_tag(is)
+        } else {
+            self.transform_tag(&jsx_element.opening.name)
+        };
+        let callee = if block_type == BlockType::Stable && !hoist {
+            if matches!(tag, Expr::Lit(Lit::Str(..))) {
+                self.import_from_vue("createElementBlock")
+            } else {
+                self.import_from_vue("createBlock")
+            }
+        } else {
+            self.get_pragma()
+        };
         let mut vnode_call_args = vec![
             ExprOrSpread {
                 spread: None,
-                expr: Box::new(self.transform_tag(&jsx_element.opening.name)),
+                expr: Box::new(tag),
             },
             ExprOrSpread {
                 spread: None,
@@ -123,10 +330,24 @@ where
             },
             ExprOrSpread {
                 spread: None,
-                expr: Box::new(self.transform_children(&jsx_element.children, is_component, slots)),
+                expr: Box::new(self.transform_children(
+                    &jsx_element.children,
+                    is_component,
+                    slots,
+                    jsx_element.span,
+                )),
             },
         ];
-        if self.options.optimize {
+        if hoist {
+            vnode_call_args.push(ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Lit(Lit::Num(Number {
+                    span: DUMMY_SP,
+                    value: PatchFlags::HOISTED.bits() as f64,
+                    raw: None,
+                }))),
+            });
+        } else if self.options.optimize {
             if !patch_flags.is_empty() {
                 vnode_call_args.push(ExprOrSpread {
                     spread: None,
@@ -159,18 +380,24 @@ where
             }
         }
 
-        let create_vnode_call = Expr::Call(CallExpr {
-            span: DUMMY_SP,
-            callee: Callee::Expr(Box::new(Expr::Ident(self.get_pragma()))),
+        let mut create_vnode_call = Expr::Call(CallExpr {
+            span: jsx_element.span,
+            callee: Callee::Expr(Box::new(Expr::Ident(callee))),
             args: vnode_call_args,
             type_args: None,
         });
+        if block_type == BlockType::Stable && !hoist {
+            create_vnode_call = self.wrap_block(create_vnode_call, false);
+        }
 
-        if directives.is_empty() {
+        if hoist {
+            self.hoisting = false;
+            Expr::Ident(self.hoist_static_vnode(create_vnode_call))
+        } else if directives.is_empty() {
             create_vnode_call
         } else {
             Expr::Call(CallExpr {
-                span: DUMMY_SP,
+                span: jsx_element.span,
                 callee: Callee::Expr(Box::new(Expr::Ident(
                     self.import_from_vue("withDirectives"),
                 ))),
@@ -215,7 +442,7 @@ where
                                     Some(ExprOrSpread {
                                         spread: None,
                                         expr: Box::new(Expr::Array(ArrayLit {
-                                            span: DUMMY_SP,
+                                            span: directive.span,
                                             elems,
                                         })),
                                     })
@@ -229,29 +456,196 @@ where
         }
     }
 
-    fn transform_jsx_fragment(&mut self, jsx_fragment: &JSXFragment) -> Expr {
+    fn hoist_static_vnode(&mut self, vnode: Expr) -> Ident {
+        let ident = private_ident!(format!("_hoisted_{}", self.hoisted_consts.len() + 1));
+        self.hoisted_consts.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(BindingIdent {
+                id: ident.clone(),
+                type_ann: None,
+            }),
+            init: Some(Box::new(vnode)),
+            definite: false,
+        });
+        ident
+    }
+
+    fn is_hoisted(&self, ident: &Ident) -> bool {
+        self.hoisted_consts.iter().any(|decl| {
+            matches!(&decl.name, Pat::Ident(BindingIdent { id, .. }) if id.to_id() == ident.to_id())
+        })
+    }
+
+    fn transform_jsx_fragment(
+        &mut self,
+        jsx_fragment: &JSXFragment,
+        block_type: BlockType,
+    ) -> Expr {
         if self.options.optimize {
             self.slot_flag_stack.push(SlotFlag::Stable);
         }
 
-        Expr::Call(CallExpr {
-            span: DUMMY_SP,
-            callee: Callee::Expr(Box::new(Expr::Ident(self.get_pragma()))),
-            args: vec![
-                ExprOrSpread {
-                    spread: None,
-                    expr: Box::new(Expr::Ident(self.import_from_vue(FRAGMENT))),
-                },
-                ExprOrSpread {
-                    spread: None,
-                    expr: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
-                },
-                ExprOrSpread {
-                    spread: None,
-                    expr: Box::new(self.transform_children(&jsx_fragment.children, false, None)),
-                },
-            ],
+        let mut args = vec![
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Ident(self.import_from_vue(FRAGMENT))),
+            },
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+            },
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(self.transform_children(
+                    &jsx_fragment.children,
+                    false,
+                    None,
+                    jsx_fragment.span,
+                )),
+            },
+        ];
+
+        let patch_flag = match block_type {
+            BlockType::None => {
+                return Expr::Call(CallExpr {
+                    span: jsx_fragment.span,
+                    callee: Callee::Expr(Box::new(Expr::Ident(self.get_pragma()))),
+                    args,
+                    type_args: None,
+                });
+            }
+            BlockType::Stable => PatchFlags::STABLE_FRAGMENT,
+            BlockType::Unstable => {
+                let mut elements = jsx_fragment
+                    .children
+                    .iter()
+                    .filter_map(|child| match child {
+                        JSXElementChild::JSXElement(jsx_element) => Some(jsx_element),
+                        _ => None,
+                    })
+                    .peekable();
+                if elements.peek().is_some() && elements.all(|jsx_element| has_key(jsx_element)) {
+                    PatchFlags::KEYED_FRAGMENT
+                } else {
+                    PatchFlags::UNKEYED_FRAGMENT
+                }
+            }
+        };
+        args.push(ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Lit(Lit::Num(Number {
+                span: DUMMY_SP,
+                value: patch_flag.bits() as f64,
+                raw: None,
+            }))),
+        });
+        let create_block_call = Expr::Call(CallExpr {
+            span: jsx_fragment.span,
+            callee: Callee::Expr(Box::new(Expr::Ident(
+                self.import_from_vue("createElementBlock"),
+            ))),
+            args,
             type_args: None,
+        });
+        // children of unstable fragments are diffed fully,
+        // so tracking dynamic descendants is disabled
+        self.wrap_block(create_block_call, block_type == BlockType::Unstable)
+    }
+
+    fn wrap_block(&mut self, create_block_call: Expr, disable_tracking: bool) -> Expr {
+        let span = create_block_call.span();
+        Expr::Paren(ParenExpr {
+            span,
+            expr: Box::new(Expr::Seq(SeqExpr {
+                span,
+                exprs: vec![
+                    Box::new(Expr::Call(CallExpr {
+                        span,
+                        callee: Callee::Expr(Box::new(Expr::Ident(
+                            self.import_from_vue("openBlock"),
+                        ))),
+                        args: if disable_tracking {
+                            vec![ExprOrSpread {
+                                spread: None,
+                                expr: Box::new(Expr::Lit(Lit::Bool(Bool {
+                                    span: DUMMY_SP,
+                                    value: true,
+                                }))),
+                            }]
+                        } else {
+                            vec![]
+                        },
+                        type_args: None,
+                    })),
+                    Box::new(create_block_call),
+                ],
+            })),
+        })
+    }
+
+    fn get_block_type(&self, expr: &Expr) -> BlockType {
+        if !self.options.optimize || self.pragma.is_some() || self.options.pragma.is_some() {
+            return BlockType::None;
+        }
+        let is_stable = match expr {
+            Expr::JSXElement(jsx_element) => self.is_stable_element(jsx_element),
+            Expr::JSXFragment(jsx_fragment) => self.are_stable_children(&jsx_fragment.children),
+            _ => return BlockType::None,
+        };
+        if is_stable {
+            BlockType::Stable
+        } else {
+            BlockType::Unstable
+        }
+    }
+
+    /// Check if the element and its descendants are always created in the same shape,
+    /// which is required by block tree since only dynamic descendants will be diffed.
+    fn is_stable_element(&self, jsx_element: &JSXElement) -> bool {
+        // cached branch isn't tracked by the parent block when the condition changes
+        if render_cache::find_v_once(jsx_element).is_some()
+            && v_if::find_condition(jsx_element).is_some()
+        {
+            return false;
+        }
+        // list rendered by `v-for` is a block itself
+        if v_for::find_v_for(jsx_element).is_some() {
+            return true;
+        }
+        // children of `KeepAlive` and `Teleport` aren't tracked by the parent block
+        !self.is_untracked_container(&jsx_element.opening.name)
+            && jsx_element
+                .opening
+                .attrs
+                .iter()
+                .all(|jsx_attr_or_spread| match jsx_attr_or_spread {
+                    JSXAttrOrSpread::JSXAttr(JSXAttr { value, .. }) => match value {
+                        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            expr: JSXExpr::Expr(expr),
+                            ..
+                        })) => !util::may_create_vnodes(expr),
+                        Some(JSXAttrValue::JSXElement(..) | JSXAttrValue::JSXFragment(..)) => false,
+                        _ => true,
+                    },
+                    JSXAttrOrSpread::SpreadElement(SpreadElement { expr, .. }) => {
+                        !util::may_create_vnodes(expr)
+                    }
+                })
+            && self.are_stable_children(&jsx_element.children)
+    }
+
+    fn are_stable_children(&self, children: &[JSXElementChild]) -> bool {
+        children.iter().all(|child| match child {
+            JSXElementChild::JSXText(..)
+            | JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::JSXEmptyExpr(..),
+                ..
+            }) => true,
+            JSXElementChild::JSXElement(jsx_element) => self.is_stable_element(jsx_element),
+            JSXElementChild::JSXFragment(jsx_fragment) => {
+                self.are_stable_children(&jsx_fragment.children)
+            }
+            JSXElementChild::JSXExprContainer(..) | JSXElementChild::JSXSpreadChild(..) => false,
         })
     }
 
@@ -259,48 +653,72 @@ where
         match jsx_element_name {
             JSXElementName::Ident(ident) => {
                 let name = &*ident.sym;
-                if name.as_bytes()[0].is_ascii_lowercase()
-                    && (css_dataset::tags::STANDARD_HTML_TAGS.contains(name)
-                        || css_dataset::tags::SVG_TAGS.contains(name))
-                {
-                    Expr::Lit(Lit::Str(quote_str!(name)))
+                if is_platform_tag(name) {
+                    Expr::Lit(Lit::Str(quote_str!(ident.span, name)))
                 } else if name == FRAGMENT {
                     Expr::Ident(self.import_from_vue(FRAGMENT))
-                } else if self
-                    .options
-                    .custom_element_patterns
-                    .iter()
-                    .any(|pattern| pattern.is_match(name))
-                {
-                    Expr::Lit(Lit::Str(quote_str!(name)))
+                } else if self.is_custom_element(name) {
+                    Expr::Lit(Lit::Str(quote_str!(ident.span, name)))
                 } else if ident.to_id().1.has_mark(self.unresolved_mark) {
-                    // for components that can't be resolved from current file
-                    Expr::Call(CallExpr {
-                        span: DUMMY_SP,
-                        callee: Callee::Expr(Box::new(Expr::Ident(
-                            self.import_from_vue("resolveComponent"),
-                        ))),
-                        args: vec![ExprOrSpread {
-                            spread: None,
-                            expr: Box::new(Expr::Lit(Lit::Str(quote_str!(name)))),
-                        }],
-                        type_args: None,
-                    })
+                    self.resolve_component(name)
                 } else {
                     Expr::Ident(ident.clone())
                 }
             }
             JSXElementName::JSXMemberExpr(expr) => Expr::JSXMember(expr.clone()),
-            JSXElementName::JSXNamespacedName(name) => Expr::JSXNamespacedName(name.clone()),
+            JSXElementName::JSXNamespacedName(namespaced_name) => {
+                let JSXNamespacedName { ns, name } = namespaced_name;
+                let tag = format!("{}:{}", ns.sym, name.sym);
+                if self.is_custom_element(&tag) {
+                    Expr::Lit(Lit::Str(quote_str!(ns.span.with_hi(name.span.hi), tag)))
+                } else {
+                    let name = self.normalize_namespaced_name(namespaced_name);
+                    self.resolve_component(&name)
+                }
+            }
+        }
+    }
+
+    /// Resolve component that can't be found in current file.
+    /// Built-in components are imported from the runtime, in either PascalCase or kebab-case,
+    /// and others are resolved by name at runtime,
+    /// where both PascalCase and kebab-case registered names are looked up.
+    fn resolve_component(&mut self, name: &str) -> Expr {
+        if let Some(built_in) = BUILT_IN_COMPONENTS
+            .iter()
+            .find(|built_in| util::is_built_in_type(name, built_in))
+        {
+            return Expr::Ident(self.import_from_vue(built_in));
+        }
+        Expr::Ident(self.resolve_asset("resolveComponent", name))
+    }
+
+    /// Convert namespaced tag like `<ui:button>` to kebab-case component name like `ui-button`.
+    /// Namespace is replaced with the prefix configured in the `componentNamespaces` option,
+    /// and it's dropped if the prefix is empty.
+    fn normalize_namespaced_name(&self, namespaced_name: &JSXNamespacedName) -> String {
+        let JSXNamespacedName { ns, name } = namespaced_name;
+        let prefix = self
+            .options
+            .component_namespaces
+            .get(&*ns.sym)
+            .map(|prefix| util::hyphenate(prefix))
+            .unwrap_or_else(|| ns.sym.to_string());
+        if prefix.is_empty() {
+            name.sym.to_string()
+        } else {
+            format!("{prefix}-{}", name.sym)
         }
     }
 
     fn transform_attrs<'a>(
         &mut self,
-        attrs: &'a [JSXAttrOrSpread],
+        jsx_opening_element: &'a JSXOpeningElement,
         is_component: bool,
         directives: &mut Vec<NormalDirective>,
     ) -> AttrsTransformationResult<'a> {
+        let JSXOpeningElement { span, attrs, .. } = jsx_opening_element;
+        let span = *span;
         let mut slots = None;
 
         if attrs.is_empty() {
@@ -329,12 +747,12 @@ where
             |(mut props, mut merge_args), jsx_attr_or_spread| {
                 match jsx_attr_or_spread {
                     JSXAttrOrSpread::JSXAttr(jsx_attr) if is_directive(jsx_attr) => {
-                        match parse_directive(jsx_attr, is_component) {
+                        match parse_directive(jsx_attr, is_component, &self.options) {
                             Directive::Normal(directive) => directives.push(directive),
                             Directive::Html(expr) => {
                                 props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
                                     KeyValueProp {
-                                        key: PropName::Str(quote_str!("innerHTML")),
+                                        key: PropName::Str(quote_str!(jsx_attr.span, "innerHTML")),
                                         value: Box::new(expr),
                                     },
                                 ))));
@@ -343,7 +761,10 @@ where
                             Directive::Text(expr) => {
                                 props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
                                     KeyValueProp {
-                                        key: PropName::Str(quote_str!("textContent")),
+                                        key: PropName::Str(quote_str!(
+                                            jsx_attr.span,
+                                            "textContent"
+                                        )),
                                         value: Box::new(expr),
                                     },
                                 ))));
@@ -356,14 +777,20 @@ where
                                             key: match &directive.argument {
                                                 Some(Expr::Lit(Lit::Null(..))) | None => {
                                                     dynamic_props.insert("modelValue".into());
-                                                    PropName::Str(quote_str!("modelValue"))
+                                                    PropName::Str(quote_str!(
+                                                        directive.span,
+                                                        "modelValue"
+                                                    ))
                                                 }
                                                 Some(Expr::Lit(Lit::Str(Str {
                                                     value, ..
                                                 }))) => {
                                                     dynamic_props
                                                         .insert(Cow::from(value.to_string()));
-                                                    PropName::Str(quote_str!(&**value))
+                                                    PropName::Str(quote_str!(
+                                                        directive.span,
+                                                        &**value
+                                                    ))
                                                 }
                                                 Some(expr) => {
                                                     PropName::Computed(ComputedPropName {
@@ -380,14 +807,18 @@ where
                                             KeyValueProp {
                                                 key: match &directive.argument {
                                                     Some(Expr::Lit(Lit::Null(..))) | None => {
-                                                        PropName::Str(quote_str!("modelModifiers"))
+                                                        PropName::Str(quote_str!(
+                                                            directive.span,
+                                                            "modelModifiers"
+                                                        ))
                                                     }
                                                     Some(Expr::Lit(Lit::Str(Str {
                                                         value,
                                                         ..
-                                                    }))) => PropName::Str(quote_str!(format!(
-                                                        "{value}Modifiers"
-                                                    ))),
+                                                    }))) => PropName::Str(quote_str!(
+                                                        directive.span,
+                                                        format!("{value}Modifiers")
+                                                    )),
                                                     Some(expr) => {
                                                         PropName::Computed(ComputedPropName {
                                                             span: DUMMY_SP,
@@ -410,6 +841,7 @@ where
                                     }
                                 } else {
                                     directives.push(NormalDirective {
+                                        span: directive.span,
                                         name: JsWord::from("model"),
                                         argument: directive.transformed_argument,
                                         modifiers: directive.modifiers.clone(),
@@ -422,11 +854,17 @@ where
                                         key: match directive.argument {
                                             Some(Expr::Lit(Lit::Null(..))) | None => {
                                                 dynamic_props.insert("onUpdate:modelValue".into());
-                                                PropName::Str(quote_str!("onUpdate:modelValue"))
+                                                PropName::Str(quote_str!(
+                                                    directive.span,
+                                                    "onUpdate:modelValue"
+                                                ))
                                             }
                                             Some(Expr::Lit(Lit::Str(Str { value, .. }))) => {
                                                 let name = format!("onUpdate:{value}");
-                                                let prop_name = PropName::Str(quote_str!(&*name));
+                                                let prop_name = PropName::Str(quote_str!(
+                                                    directive.span,
+                                                    &*name
+                                                ));
                                                 dynamic_props.insert(name.into());
                                                 prop_name
                                             }
@@ -445,32 +883,7 @@ where
                                                 })
                                             }
                                         },
-                                        value: Box::new(Expr::Arrow(ArrowExpr {
-                                            span: DUMMY_SP,
-                                            params: vec![Pat::Ident(BindingIdent {
-                                                id: quote_ident!("$event"),
-                                                type_ann: None,
-                                            })],
-                                            body: Box::new(BlockStmtOrExpr::Expr(Box::new(
-                                                Expr::Assign(AssignExpr {
-                                                    span: DUMMY_SP,
-                                                    op: op!("="),
-                                                    left: AssignTarget::Simple(
-                                                        SimpleAssignTarget::Paren(ParenExpr {
-                                                            span: DUMMY_SP,
-                                                            expr: Box::new(directive.value)
-                                                        }),
-                                                    ),
-                                                    right: Box::new(Expr::Ident(quote_ident!(
-                                                        "$event"
-                                                    ))),
-                                                }),
-                                            ))),
-                                            is_async: false,
-                                            is_generator: false,
-                                            type_params: None,
-                                            return_type: None,
-                                        })),
+                                        value: Box::new(directive.updater),
                                     },
                                 ))));
                             }
@@ -489,7 +902,7 @@ where
                             .as_ref()
                             .map(|value| match value {
                                 JSXAttrValue::Lit(Lit::Str(str)) => Box::new(Expr::Lit(Lit::Str(
-                                    quote_str!(util::transform_text(&str.value)),
+                                    quote_str!(str.span, util::transform_text(&str.value)),
                                 ))),
                                 JSXAttrValue::Lit(..) => {
                                     unreachable!("JSX attribute value literal must be string")
@@ -511,7 +924,7 @@ where
                             })
                             .unwrap_or_else(|| {
                                 Box::new(Expr::Lit(Lit::Bool(Bool {
-                                    span: DUMMY_SP,
+                                    span: jsx_attr.span,
                                     value: true,
                                 })))
                             });
@@ -547,7 +960,7 @@ where
                             && (attr_name == "on" || attr_name == "nativeOn")
                         {
                             merge_args.push(Expr::Call(CallExpr {
-                                span: DUMMY_SP,
+                                span: jsx_attr.span,
                                 callee: Callee::Expr(Box::new(Expr::Ident(
                                     self.transform_on_helper
                                         .get_or_insert_with(|| private_ident!("_transformOn"))
@@ -562,7 +975,7 @@ where
                         } else {
                             props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
                                 KeyValueProp {
-                                    key: PropName::Str(quote_str!(attr_name)),
+                                    key: PropName::Str(quote_str!(jsx_attr.span, attr_name)),
                                     value: attr_value,
                                 },
                             ))));
@@ -573,7 +986,7 @@ where
 
                         if !props.is_empty() && self.options.merge_props {
                             merge_args.push(Expr::Object(ObjectLit {
-                                span: DUMMY_SP,
+                                span,
                                 props: util::dedupe_props(mem::take(&mut props)),
                             }));
                         }
@@ -598,7 +1011,7 @@ where
         let expr = if !merge_args.is_empty() {
             if !props.is_empty() {
                 merge_args.push(Expr::Object(ObjectLit {
-                    span: DUMMY_SP,
+                    span,
                     props: if self.options.merge_props {
                         util::dedupe_props(mem::take(&mut props))
                     } else {
@@ -609,7 +1022,7 @@ where
             match merge_args.as_slice() {
                 [expr] => expr.clone(),
                 _ => Expr::Call(CallExpr {
-                    span: DUMMY_SP,
+                    span,
                     callee: Callee::Expr(Box::new(Expr::Ident(self.import_from_vue("mergeProps")))),
                     args: merge_args
                         .into_iter()
@@ -626,7 +1039,7 @@ where
                 *expr.clone()
             } else {
                 Expr::Object(ObjectLit {
-                    span: DUMMY_SP,
+                    span,
                     props: if self.options.merge_props {
                         util::dedupe_props(props)
                     } else {
@@ -674,63 +1087,17 @@ where
         children: &[JSXElementChild],
         is_component: bool,
         slots: Option<Box<Expr>>,
+        span: Span,
     ) -> Expr {
-        let elems = children
-            .iter()
+        let mut v_if_key = 0;
+        let elems = v_if::group_v_if_chains(children)
+            .into_iter()
             .filter_map(|child| match child {
-                JSXElementChild::JSXText(jsx_text) => {
-                    self.transform_jsx_text(jsx_text).map(|expr| ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(expr),
-                    })
-                }
-                JSXElementChild::JSXExprContainer(JSXExprContainer {
-                    expr: JSXExpr::JSXEmptyExpr(..),
-                    ..
-                }) => None,
-                JSXElementChild::JSXExprContainer(JSXExprContainer {
-                    expr: JSXExpr::Expr(expr),
-                    ..
-                }) => {
-                    if self.options.optimize {
-                        match &**expr {
-                            Expr::Ident(ident)
-                                if !ident.to_id().1.has_mark(self.unresolved_mark) =>
-                            {
-                                self.slot_flag_stack.fill(SlotFlag::Dynamic);
-                            }
-                            _ => {}
-                        }
-                    }
-                    Some(ExprOrSpread {
-                        spread: None,
-                        expr: expr.clone(),
-                    })
-                }
-                JSXElementChild::JSXSpreadChild(JSXSpreadChild { expr, .. }) => {
-                    if self.options.optimize {
-                        match &**expr {
-                            Expr::Ident(ident)
-                                if !ident.to_id().1.has_mark(self.unresolved_mark) =>
-                            {
-                                self.slot_flag_stack.fill(SlotFlag::Dynamic);
-                            }
-                            _ => {}
-                        }
-                    }
-                    Some(ExprOrSpread {
-                        spread: Some(DUMMY_SP),
-                        expr: expr.clone(),
-                    })
-                }
-                JSXElementChild::JSXElement(jsx_element) => Some(ExprOrSpread {
+                ChildOrVIfChain::VIfChain(chain) => Some(ExprOrSpread {
                     spread: None,
-                    expr: Box::new(self.transform_jsx_element(jsx_element)),
-                }),
-                JSXElementChild::JSXFragment(jsx_fragment) => Some(ExprOrSpread {
-                    spread: None,
-                    expr: Box::new(self.transform_jsx_fragment(jsx_fragment)),
+                    expr: Box::new(self.transform_v_if_chain(&chain, &mut v_if_key)),
                 }),
+                ChildOrVIfChain::Child(child) => self.transform_child(child),
             })
             .map(Some)
             .collect::<Vec<_>>();
@@ -750,7 +1117,7 @@ where
                 }
             }
             [Some(ExprOrSpread { spread: None, expr })] => match &**expr {
-                expr @ Expr::Ident(..) if is_component => {
+                expr @ Expr::Ident(ident) if is_component && !self.is_hoisted(ident) => {
                     let elems = self.build_iife(elems.clone());
                     if self.options.enable_object_slots {
                         Expr::Cond(CondExpr {
@@ -767,14 +1134,13 @@ where
                                 type_args: None,
                             })),
                             cons: Box::new(expr.clone()),
-                            alt: Box::new(self.wrap_children(elems, slot_flag, slots)),
+                            alt: Box::new(self.wrap_children(elems, slot_flag, slots, span)),
                         })
                     } else {
-                        self.wrap_children(elems, slot_flag, slots)
+                        self.wrap_children(elems, slot_flag, slots, span)
                     }
                 }
-                expr @ Expr::Call(..) if expr.span() != DUMMY_SP && is_component => {
-                    // the element was generated and doesn't have location information
+                expr @ Expr::Call(call) if !self.is_vnode_call(call) && is_component => {
                     if self.options.enable_object_slots {
                         let slot_ident = self.generate_unique_slot_ident();
                         Expr::Cond(CondExpr {
@@ -790,10 +1156,10 @@ where
                                         span: DUMMY_SP,
                                         op: op!("="),
                                         left: AssignTarget::Simple(SimpleAssignTarget::Paren(
-                                            ParenExpr{
+                                            ParenExpr {
                                                 span: DUMMY_SP,
                                                 expr: Box::new(Expr::Ident(slot_ident.clone())),
-                                            }
+                                            },
                                         )),
                                         right: Box::new(expr.clone()),
                                     })),
@@ -806,15 +1172,15 @@ where
                                     spread: None,
                                     expr: Box::new(Expr::Ident(slot_ident)),
                                 })]);
-                                Box::new(self.wrap_children(elems, slot_flag, slots))
+                                Box::new(self.wrap_children(elems, slot_flag, slots, span))
                             },
                         })
                     } else {
-                        self.wrap_children(elems, slot_flag, slots)
+                        self.wrap_children(elems, slot_flag, slots, span)
                     }
                 }
                 expr @ Expr::Fn(..) | expr @ Expr::Arrow(..) => Expr::Object(ObjectLit {
-                    span: DUMMY_SP,
+                    span,
                     props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
                         key: PropName::Ident(quote_ident!("default")),
                         value: Box::new(expr.clone()),
@@ -832,32 +1198,77 @@ where
                             }))),
                         }))));
                     }
-                    Expr::Object(ObjectLit {
-                        span: DUMMY_SP,
-                        props,
-                    })
+                    Expr::Object(ObjectLit { span, props })
                 }
                 _ => {
                     if is_component {
-                        self.wrap_children(elems, slot_flag, slots)
+                        self.wrap_children(elems, slot_flag, slots, span)
                     } else {
-                        Expr::Array(ArrayLit {
-                            span: DUMMY_SP,
-                            elems,
-                        })
+                        Expr::Array(ArrayLit { span, elems })
                     }
                 }
             },
             _ => {
                 if is_component {
-                    self.wrap_children(elems, slot_flag, slots)
+                    self.wrap_children(elems, slot_flag, slots, span)
                 } else {
-                    Expr::Array(ArrayLit {
-                        span: DUMMY_SP,
-                        elems,
-                    })
+                    Expr::Array(ArrayLit { span, elems })
+                }
+            }
+        }
+    }
+
+    fn transform_child(&mut self, child: &JSXElementChild) -> Option<ExprOrSpread> {
+        match child {
+            JSXElementChild::JSXText(jsx_text) => {
+                self.transform_jsx_text(jsx_text).map(|expr| ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(expr),
+                })
+            }
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::JSXEmptyExpr(..),
+                ..
+            }) => None,
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => {
+                if self.options.optimize {
+                    match &**expr {
+                        Expr::Ident(ident) if !ident.to_id().1.has_mark(self.unresolved_mark) => {
+                            self.slot_flag_stack.fill(SlotFlag::Dynamic);
+                        }
+                        _ => {}
+                    }
                 }
+                Some(ExprOrSpread {
+                    spread: None,
+                    expr: expr.clone(),
+                })
+            }
+            JSXElementChild::JSXSpreadChild(JSXSpreadChild { expr, .. }) => {
+                if self.options.optimize {
+                    match &**expr {
+                        Expr::Ident(ident) if !ident.to_id().1.has_mark(self.unresolved_mark) => {
+                            self.slot_flag_stack.fill(SlotFlag::Dynamic);
+                        }
+                        _ => {}
+                    }
+                }
+                Some(ExprOrSpread {
+                    spread: Some(DUMMY_SP),
+                    expr: expr.clone(),
+                })
             }
+            JSXElementChild::JSXElement(jsx_element) => Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(self.transform_jsx_element(jsx_element, BlockType::None)),
+            }),
+            JSXElementChild::JSXFragment(jsx_fragment) => Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(self.transform_jsx_fragment(jsx_fragment, BlockType::None)),
+            }),
         }
     }
 
@@ -866,14 +1277,15 @@ where
         elems: Vec<Option<ExprOrSpread>>,
         slot_flag: SlotFlag,
         slots: Option<Box<Expr>>,
+        span: Span,
     ) -> Expr {
         let mut props = vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
             key: PropName::Ident(quote_ident!("default")),
             value: Box::new(Expr::Arrow(ArrowExpr {
-                span: DUMMY_SP,
+                span,
                 params: vec![],
                 body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Array(ArrayLit {
-                    span: DUMMY_SP,
+                    span,
                     elems,
                 })))),
                 is_async: false,
@@ -906,10 +1318,7 @@ where
             }))));
         }
 
-        Expr::Object(ObjectLit {
-            span: DUMMY_SP,
-            props,
-        })
+        Expr::Object(ObjectLit { span, props })
     }
 
     fn generate_unique_slot_ident(&mut self) -> Ident {
@@ -938,13 +1347,13 @@ where
             None
         } else {
             Some(Expr::Call(CallExpr {
-                span: DUMMY_SP,
+                span: jsx_text.span,
                 callee: Callee::Expr(Box::new(Expr::Ident(
                     self.import_from_vue("createTextVNode"),
                 ))),
                 args: vec![ExprOrSpread {
                     spread: None,
-                    expr: Box::new(Expr::Lit(Lit::Str(quote_str!(text)))),
+                    expr: Box::new(Expr::Lit(Lit::Str(quote_str!(jsx_text.span, text)))),
                 }],
                 type_args: None,
             }))
@@ -952,6 +1361,17 @@ where
     }
 
     fn resolve_directive(&mut self, directive_name: &str, jsx_element: &JSXElement) -> Expr {
+        // built-in directives can't bind value of editable elements,
+        // so it's up to the directive configured by user
+        if let Some(directive) = self
+            .options
+            .content_editable_directive
+            .clone()
+            .filter(|_| directive_name == "model" && is_content_editable(jsx_element))
+        {
+            return Expr::Ident(self.resolve_asset("resolveDirective", &directive));
+        }
+
         match directive_name {
             "show" => Expr::Ident(self.import_from_vue("vShow")),
             "model" => match &jsx_element.opening.name {
@@ -984,51 +1404,147 @@ where
                         Some(JSXAttrValue::Lit(Lit::Str(..))) | None => {
                             Expr::Ident(self.import_from_vue("vModelText"))
                         }
-                        Some(..) => Expr::Ident(self.import_from_vue("vModelDynamic")),
+                        Some(value) => {
+                            let may_be_string = match value {
+                                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                                    expr: JSXExpr::Expr(expr),
+                                    ..
+                                }) => {
+                                    !matches!(&**expr, Expr::Lit(lit) if !matches!(lit, Lit::Str(..)))
+                                }
+                                _ => false,
+                            };
+                            if !may_be_string {
+                                Diagnostic::NonStringVModelType.emit(value.span(), &self.options);
+                            }
+                            Expr::Ident(self.import_from_vue("vModelDynamic"))
+                        }
                     }
                 }
             },
-            _ => Expr::Call(CallExpr {
-                span: DUMMY_SP,
-                callee: Callee::Expr(Box::new(Expr::Ident(
-                    self.import_from_vue("resolveDirective"),
-                ))),
-                args: vec![ExprOrSpread {
-                    spread: None,
-                    expr: Box::new(Expr::Lit(Lit::Str(quote_str!(directive_name)))),
-                }],
-                type_args: None,
-            }),
+            _ => Expr::Ident(self.resolve_asset("resolveDirective", directive_name)),
         }
     }
 
+    /// Check if the call is a vnode created from JSX element or text,
+    /// rather than a call written by user in JSX expression container.
+    fn is_vnode_call(&self, call: &CallExpr) -> bool {
+        let Callee::Expr(callee) = &call.callee else {
+            return false;
+        };
+        let Expr::Ident(callee) = &**callee else {
+            return false;
+        };
+        self.vue_imports
+            .values()
+            .any(|ident| ident.to_id() == callee.to_id())
+            || self
+                .pragma
+                .as_ref()
+                .or(self.options.pragma.as_ref())
+                .is_some_and(|pragma| {
+                    callee.span.ctxt == SyntaxContext::empty() && &*callee.sym == pragma
+                })
+    }
+
     fn is_component(&self, element_name: &JSXElementName) -> bool {
         let name = match element_name {
             JSXElementName::Ident(Ident { sym, .. }) => sym,
             JSXElementName::JSXMemberExpr(JSXMemberExpr { prop, .. }) => &*prop.sym,
-            JSXElementName::JSXNamespacedName(JSXNamespacedName { name, .. }) => &*name.sym,
+            JSXElementName::JSXNamespacedName(namespaced_name) => {
+                return !self.is_untracked_container(element_name)
+                    && !self.is_custom_element(&format!(
+                        "{}:{}",
+                        namespaced_name.ns.sym, namespaced_name.name.sym
+                    ));
+            }
         };
         let should_transformed_to_slots = !self
             .vue_imports
             .get(FRAGMENT)
+            .or_else(|| self.user_vue_imports.get(&JsWord::from(FRAGMENT)))
             .map(|ident| &*ident.sym == name)
             .unwrap_or_default()
-            && name != KEEP_ALIVE;
+            && !self.is_untracked_container(element_name);
 
         if matches!(element_name, JSXElementName::JSXMemberExpr(..)) {
             should_transformed_to_slots
         } else {
-            self.options
-                .custom_element_patterns
-                .iter()
-                .all(|pattern| !pattern.is_match(name))
-                && should_transformed_to_slots
-                && !(name.as_bytes()[0].is_ascii_lowercase()
-                    && (css_dataset::tags::STANDARD_HTML_TAGS.contains(name)
-                        || css_dataset::tags::SVG_TAGS.contains(name)))
+            should_transformed_to_slots && !self.is_native_tag(name)
         }
     }
 
+    /// Check if the tag is `KeepAlive` or `Teleport` of runtime,
+    /// whose children are passed as an array and aren't tracked by the parent block.
+    /// Components declared by user with the same name are left as they are.
+    fn is_untracked_container(&self, element_name: &JSXElementName) -> bool {
+        [KEEP_ALIVE, TELEPORT]
+            .into_iter()
+            .any(|built_in| match element_name {
+                JSXElementName::Ident(ident) => {
+                    if ident.span.ctxt().has_mark(self.unresolved_mark) {
+                        util::is_built_in_type(&ident.sym, built_in)
+                    } else {
+                        self.user_vue_imports
+                            .get(&JsWord::from(built_in))
+                            .is_some_and(|imported| imported.to_id() == ident.to_id())
+                    }
+                }
+                JSXElementName::JSXMemberExpr(JSXMemberExpr { prop, .. }) => prop.sym == built_in,
+                JSXElementName::JSXNamespacedName(namespaced_name) => util::is_built_in_type(
+                    &self.normalize_namespaced_name(namespaced_name),
+                    built_in,
+                ),
+            })
+    }
+
+    fn is_custom_element(&self, name: &str) -> bool {
+        let custom_elements = &self.options.custom_elements;
+        (self
+            .options
+            .custom_element_patterns
+            .iter()
+            .any(|pattern| pattern.is_match(name))
+            || custom_elements.is_match(name))
+            && !custom_elements.is_excluded(name)
+    }
+
+    /// Check if the tag is created as a plain element instead of a component.
+    fn is_native_tag(&self, name: &str) -> bool {
+        is_platform_tag(name) || self.is_custom_element(name)
+    }
+
+    /// Check if the tag, attributes and children of an element are all static,
+    /// so it can be created once and reused across renders.
+    fn is_static_element(&self, jsx_element: &JSXElement) -> bool {
+        matches!(&jsx_element.opening.name, JSXElementName::Ident(ident) if self.is_native_tag(&ident.sym))
+            && jsx_element
+                .opening
+                .attrs
+                .iter()
+                .all(|jsx_attr_or_spread| match jsx_attr_or_spread {
+                    JSXAttrOrSpread::JSXAttr(jsx_attr) => {
+                        !is_directive(jsx_attr)
+                            && !matches!(&jsx_attr.name, JSXAttrName::Ident(ident) if ident.sym == "ref")
+                            && jsx_attr
+                                .value
+                                .as_ref()
+                                .map(util::is_jsx_attr_value_constant)
+                                .unwrap_or(true)
+                    }
+                    JSXAttrOrSpread::SpreadElement(..) => false,
+                })
+            && jsx_element.children.iter().all(|child| match child {
+                JSXElementChild::JSXText(..)
+                | JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::JSXEmptyExpr(..),
+                    ..
+                }) => true,
+                JSXElementChild::JSXElement(jsx_element) => self.is_static_element(jsx_element),
+                _ => false,
+            })
+    }
+
     fn get_pragma(&mut self) -> Ident {
         self.pragma
             .as_ref()
@@ -1132,14 +1648,58 @@ where
     C: Comments,
 {
     fn visit_mut_module(&mut self, module: &mut Module) {
+        if self.options.resolve_type && self.type_resolver.is_none() {
+            self.type_resolver = Some(Box::new(self.create_type_resolver()));
+        }
         self.search_jsx_pragma(module.span);
         module
             .body
             .iter()
             .for_each(|item| self.search_jsx_pragma(item.span()));
+        self.collect_user_vue_imports(module);
+        if self.options.runtime_global.is_some() {
+            self.collect_user_vue_requires(module.body.iter().filter_map(ModuleItem::as_stmt));
+        }
 
         module.visit_mut_children_with(self);
 
+        // HMR runtime only exists in browser
+        if self.options.hmr && !self.options.ssr {
+            self.inject_hmr_code(module);
+        }
+
+        // helpers destructured by user can't be accessed before their declarations
+        let index = self
+            .options
+            .runtime_global
+            .as_ref()
+            .and_then(|_| {
+                self.find_after_user_vue_requires(module.body.iter().map(ModuleItem::as_stmt))
+            })
+            .unwrap_or_else(|| {
+                module
+                    .body
+                    .iter()
+                    .position(|item| {
+                        !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(..)))
+                    })
+                    .unwrap_or(module.body.len())
+            });
+        if !self.hoisted_consts.is_empty() {
+            module.body.insert(
+                index,
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Const,
+                    declare: false,
+                    decls: mem::take(&mut self.hoisted_consts),
+                })))),
+            );
+        }
+        if let Some(assets) = self.take_module_assets() {
+            module.body.insert(index, ModuleItem::Stmt(assets));
+        }
+
         if !self.injecting_consts.is_empty() {
             module.body.insert(
                 0,
@@ -1192,13 +1752,13 @@ where
             )
         }
 
-        if !self.vue_imports.is_empty() {
+        if !self.server_renderer_imports.is_empty() {
             module.body.insert(
                 0,
                 ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
                     span: DUMMY_SP,
                     specifiers: self
-                        .vue_imports
+                        .server_renderer_imports
                         .iter()
                         .map(|(imported, local)| {
                             ImportSpecifier::Named(ImportNamedSpecifier {
@@ -1209,13 +1769,85 @@ where
                             })
                         })
                         .collect(),
-                    src: Box::new(quote_str!("vue")),
+                    src: Box::new(quote_str!("@vue/server-renderer")),
                     type_only: false,
                     with: None,
                     phase: Default::default(),
                 })),
             );
         }
+
+        let vue_imports = self.collect_vue_imports();
+        if vue_imports.is_empty() {
+            return;
+        }
+        if let Some(runtime_global) = &self.options.runtime_global {
+            module.body.insert(
+                0,
+                ModuleItem::Stmt(util::build_destructuring(
+                    vue_imports.into_iter(),
+                    util::build_global_access(runtime_global),
+                )),
+            );
+            return;
+        }
+
+        let specifiers = vue_imports
+            .into_iter()
+            .map(|(imported, local)| {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    local,
+                    imported: Some(ModuleExportName::Ident(quote_ident!(imported))),
+                    is_type_only: false,
+                })
+            })
+            .collect::<Vec<_>>();
+        // named imports can't be merged into namespace import
+        let user_import_decl = module.body.iter_mut().find_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl))
+                if !import_decl.type_only
+                    && import_decl.src.value == *self.options.import_source
+                    && !import_decl
+                        .specifiers
+                        .iter()
+                        .any(|specifier| specifier.is_namespace()) =>
+            {
+                Some(import_decl)
+            }
+            _ => None,
+        });
+        if let Some(import_decl) = user_import_decl {
+            import_decl.specifiers.extend(specifiers);
+        } else {
+            module.body.insert(
+                0,
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers,
+                    src: Box::new(quote_str!(&*self.options.import_source)),
+                    type_only: false,
+                    with: None,
+                    phase: Default::default(),
+                })),
+            );
+        }
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        if self.options.resolve_type && self.type_resolver.is_none() {
+            self.type_resolver = Some(Box::new(self.create_type_resolver()));
+        }
+        self.search_jsx_pragma(script.span);
+        script
+            .body
+            .iter()
+            .for_each(|stmt| self.search_jsx_pragma(stmt.span()));
+        self.collect_user_vue_requires(script.body.iter());
+
+        script.visit_mut_children_with(self);
+
+        self.inject_script_helpers(script);
     }
 
     fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
@@ -1248,7 +1880,13 @@ where
     }
 
     fn visit_mut_arrow_expr(&mut self, arrow_expr: &mut ArrowExpr) {
+        let outer_render_cache = self.enter_render_fn(arrow_expr);
+        self.enter_asset_scope();
         arrow_expr.visit_mut_children_with(self);
+        if let Some(outer_render_cache) = outer_render_cache {
+            self.leave_render_fn(arrow_expr, outer_render_cache);
+        }
+        let assets = self.leave_asset_scope();
 
         if !self.injecting_consts.is_empty() || !self.injecting_vars.is_empty() {
             if let BlockStmtOrExpr::Expr(ret) = &*arrow_expr.body {
@@ -1278,27 +1916,57 @@ where
                     arg: Some(ret.clone()),
                 }));
 
-                arrow_expr.body = Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                *arrow_expr.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
                     span: DUMMY_SP,
                     stmts,
-                }));
+                });
+            }
+        }
+
+        if let Some(assets) = assets {
+            match &mut *arrow_expr.body {
+                BlockStmtOrExpr::BlockStmt(block) => block.stmts.insert(0, assets),
+                BlockStmtOrExpr::Expr(ret) => {
+                    *arrow_expr.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts: vec![
+                            assets,
+                            Stmt::Return(ReturnStmt {
+                                span: DUMMY_SP,
+                                arg: Some(ret.clone()),
+                            }),
+                        ],
+                    });
+                }
             }
         }
     }
 
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        self.enter_asset_scope();
+        function.visit_mut_children_with(self);
+        if let (Some(assets), Some(body)) = (self.leave_asset_scope(), &mut function.body) {
+            body.stmts.insert(0, assets);
+        }
+    }
+
     fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        // block stability must be checked before nested JSX in attributes is transformed
+        let block_type = self.get_block_type(expr);
         expr.visit_mut_children_with(self);
 
         match expr {
-            Expr::JSXElement(jsx_element) => *expr = self.transform_jsx_element(jsx_element),
-            Expr::JSXFragment(jsx_fragment) => *expr = self.transform_jsx_fragment(jsx_fragment),
+            Expr::JSXElement(jsx_element) => {
+                *expr = self.transform_jsx_element(jsx_element, block_type)
+            }
+            Expr::JSXFragment(jsx_fragment) => {
+                *expr = self.transform_jsx_fragment(jsx_fragment, block_type)
+            }
             Expr::Assign(AssignExpr {
-                left: AssignTarget::Simple(simple_assign_target),
+                left: AssignTarget::Simple(SimpleAssignTarget::Ident(binding_ident)),
                 ..
             }) => {
-                if let SimpleAssignTarget::Ident(binding_ident) = &*simple_assign_target {
-                    self.assignment_left = Some(binding_ident.id.clone());
-                }
+                self.assignment_left = Some(binding_ident.id.clone());
             }
             _ => {}
         }
@@ -1335,21 +2003,11 @@ where
             ..
         })) = value
         else {
-            HANDLER.with(|handler| {
-                handler.span_err(
-                    value.span(),
-                    "you should pass a Two-dimensional Arrays to v-models",
-                )
-            });
+            Diagnostic::InvalidVModels.emit(value.span(), &self.options);
             return;
         };
         let Expr::Array(ArrayLit { elems, .. }) = *expr else {
-            HANDLER.with(|handler| {
-                handler.span_err(
-                    expr.span(),
-                    "you should pass a Two-dimensional Arrays to v-models",
-                )
-            });
+            Diagnostic::InvalidVModels.emit(expr.span(), &self.options);
             return;
         };
 
@@ -1361,7 +2019,12 @@ where
     fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
         import_decl.visit_mut_children_with(self);
 
-        if import_decl.src.value != "vue" {
+        if import_decl.src.value != *self.options.import_source {
+            if self.options.resolve_type {
+                if let Some(filename) = self.filename.clone() {
+                    self.register_imported_types(&filename, import_decl);
+                }
+            }
             return;
         }
 
@@ -1385,36 +2048,37 @@ where
     fn visit_mut_ts_interface_decl(&mut self, ts_interface_decl: &mut TsInterfaceDecl) {
         ts_interface_decl.visit_mut_children_with(self);
         if self.options.resolve_type {
-            let key = (
-                ts_interface_decl.id.sym.clone(),
-                ts_interface_decl.id.span.ctxt(),
-            );
-            if let Some(interface) = self.interfaces.get_mut(&key) {
-                interface
-                    .body
-                    .body
-                    .extend_from_slice(&ts_interface_decl.body.body);
-            } else {
-                self.interfaces.insert(key, ts_interface_decl.clone());
-            }
+            self.collect_ts_interface(ts_interface_decl);
         }
     }
 
     fn visit_mut_ts_type_alias_decl(&mut self, ts_type_alias_decl: &mut TsTypeAliasDecl) {
         ts_type_alias_decl.visit_mut_children_with(self);
         if self.options.resolve_type {
-            self.type_aliases.insert(
-                (
-                    ts_type_alias_decl.id.sym.clone(),
-                    ts_type_alias_decl.id.span.ctxt(),
-                ),
-                (*ts_type_alias_decl.type_ann).clone(),
-            );
+            self.collect_ts_type_alias(ts_type_alias_decl);
         }
     }
 
     fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
-        call_expr.visit_mut_children_with(self);
+        // render functions must be compiled before JSX in them is transformed
+        if self.options.ssr
+            && self.pragma.is_none()
+            && self.options.pragma.is_none()
+            && self.is_define_component_call(call_expr)
+        {
+            self.transform_ssr_component(call_expr);
+        }
+        if !self.options.ssr && self.is_define_component_call(call_expr) {
+            self.prepare_render_cache(call_expr);
+        }
+
+        call_expr.callee.visit_mut_with(self);
+        for arg in &mut call_expr.args {
+            self.visiting_callback = matches!(&*arg.expr, Expr::Arrow(..) | Expr::Fn(..));
+            arg.visit_mut_with(self);
+        }
+        self.visiting_callback = false;
+        call_expr.type_args.visit_mut_with(self);
 
         if !self.options.resolve_type {
             return;
@@ -1428,13 +2092,36 @@ where
             return;
         };
 
-        let props_types = self.extract_props_type(maybe_setup);
-        let emits_types = self.extract_emits_type(maybe_setup);
+        // runtime options are injected as the second argument,
+        // which is only accepted by the setup function form
+        let is_setup_fn = match maybe_setup {
+            ExprOrSpread { expr, spread: None } => match &**expr {
+                Expr::Arrow(arrow) => {
+                    self.load_referenced_types(&arrow.params);
+                    true
+                }
+                Expr::Fn(fn_expr) => {
+                    self.load_referenced_types(&fn_expr.function.params);
+                    true
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+        if !is_setup_fn {
+            return;
+        }
+        self.load_referenced_types(&call_expr.type_args);
+        let type_args = call_expr.type_args.as_deref();
+        let mut props_types = self.extract_props_type(maybe_setup, type_args);
+        let mut emits_types = self.extract_emits_type(maybe_setup, type_args);
+        self.inject_models(props_types.as_mut(), &mut emits_types);
         if let Some(prop_types) = props_types {
             inject_define_component_option(call_expr, "props", prop_types);
         }
         if let Some(emits_type) = emits_types {
-            inject_define_component_option(call_expr, "emits", Expr::Array(emits_type));
+            let emits = self.build_emits_option(emits_type);
+            inject_define_component_option(call_expr, "emits", emits);
         }
     }
 
@@ -1462,6 +2149,44 @@ where
     }
 }
 
+fn has_key(jsx_element: &JSXElement) -> bool {
+    jsx_element.opening.attrs.iter().any(|jsx_attr_or_spread| {
+        matches!(
+            jsx_attr_or_spread,
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(ident),
+                ..
+            }) if ident.sym == "key"
+        )
+    })
+}
+
+fn is_content_editable(jsx_element: &JSXElement) -> bool {
+    jsx_element
+        .opening
+        .attrs
+        .iter()
+        .any(|jsx_attr_or_spread| match jsx_attr_or_spread {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(ident),
+                value,
+                ..
+            }) if ident.sym.eq_ignore_ascii_case("contenteditable") => !matches!(
+                value,
+                Some(JSXAttrValue::Lit(Lit::Str(str))) if &str.value == "false"
+            ),
+            _ => false,
+        })
+}
+
+/// Check if the tag is a standard HTML, SVG or MathML element.
+fn is_platform_tag(name: &str) -> bool {
+    name.as_bytes()[0].is_ascii_lowercase()
+        && (css_dataset::tags::STANDARD_HTML_TAGS.contains(name)
+            || css_dataset::tags::SVG_TAGS.contains(name)
+            || css_dataset::tags::MATH_ML_TAGS.contains(name))
+}
+
 fn inject_define_component_option(call: &mut CallExpr, name: &'static str, value: Expr) {
     let options = call.args.get_mut(1);
     if options
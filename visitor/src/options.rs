@@ -2,7 +2,7 @@ use serde::{
     de::{Error, Unexpected, Visitor},
     Deserialize, Deserializer,
 };
-use std::{fmt, ops::Deref};
+use std::{collections::HashMap, fmt, ops::Deref};
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
@@ -10,10 +10,20 @@ pub struct Options {
     pub transform_on: bool,
     pub optimize: bool,
     pub custom_element_patterns: Vec<Regex>,
+    pub custom_elements: CustomElements,
+    pub component_namespaces: HashMap<String, String>,
+    pub content_editable_directive: Option<String>,
     pub merge_props: bool,
     pub enable_object_slots: bool,
     pub pragma: Option<String>,
+    pub import_source: String,
+    pub runtime_global: Option<String>,
     pub resolve_type: bool,
+    pub validate_emits: bool,
+    pub tsconfig: Option<String>,
+    pub ssr: bool,
+    pub hmr: bool,
+    pub warnings_as_errors: bool,
 }
 
 impl Default for Options {
@@ -22,14 +32,56 @@ impl Default for Options {
             transform_on: false,
             optimize: false,
             custom_element_patterns: Default::default(),
+            custom_elements: Default::default(),
+            component_namespaces: Default::default(),
+            content_editable_directive: None,
             merge_props: true,
             enable_object_slots: true,
             pragma: None,
+            import_source: "vue".into(),
+            runtime_global: None,
             resolve_type: false,
+            validate_emits: false,
+            tsconfig: None,
+            ssr: false,
+            hmr: false,
+            warnings_as_errors: false,
         }
     }
 }
 
+/// Declarative matchers of custom elements, besides the `customElementPatterns` option.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CustomElements {
+    /// Exact tag names.
+    pub names: Vec<String>,
+    /// Prefixes of tag names, like `ion-`.
+    pub prefixes: Vec<String>,
+    /// Glob patterns of tag names, like `ds-*-item`.
+    pub globs: Vec<Glob>,
+    /// Tags matching any of these regex are never treated as custom elements,
+    /// even if they're matched by other options.
+    pub exclude_patterns: Vec<Regex>,
+}
+
+impl CustomElements {
+    pub fn is_match(&self, name: &str) -> bool {
+        self.names.iter().any(|element| element == name)
+            || self
+                .prefixes
+                .iter()
+                .any(|prefix| name.starts_with(&**prefix))
+            || self.globs.iter().any(|glob| glob.is_match(name))
+    }
+
+    pub fn is_excluded(&self, name: &str) -> bool {
+        self.exclude_patterns
+            .iter()
+            .any(|pattern| pattern.is_match(name))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Regex(regex::Regex);
 
@@ -90,3 +142,39 @@ impl<'de> Visitor<'de> for RegexVisitor {
             .map_err(|_| E::invalid_value(Unexpected::Str(&v), &"a valid regex"))
     }
 }
+
+/// Glob pattern where `*` matches any characters and `?` matches a single character.
+#[derive(Clone, Debug)]
+pub struct Glob(regex::Regex);
+
+impl Glob {
+    pub fn new(glob: &str) -> Result<Self, regex::Error> {
+        let mut re = String::with_capacity(glob.len() + 2);
+        re.push('^');
+        let mut buf = [0; 4];
+        for c in glob.chars() {
+            match c {
+                '*' => re.push_str(".*"),
+                '?' => re.push('.'),
+                _ => re.push_str(&regex::escape(c.encode_utf8(&mut buf))),
+            }
+        }
+        re.push('$');
+        regex::Regex::new(&re).map(Self)
+    }
+
+    pub fn is_match(&self, name: &str) -> bool {
+        self.0.is_match(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for Glob {
+    fn deserialize<D>(deserializer: D) -> Result<Glob, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let glob = String::deserialize(deserializer)?;
+        Glob::new(&glob)
+            .map_err(|_| D::Error::invalid_value(Unexpected::Str(&glob), &"a valid glob"))
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{diagnostics::Diagnostic, util, v_for, BlockType, VueJsxTransformVisitor};
+use std::mem;
+use swc_core::{
+    common::{comments::Comments, Spanned, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{private_ident, quote_ident},
+    },
+};
+
+/// Cache of the render function, which is passed by Vue as the second argument.
+pub(crate) struct RenderCache {
+    ident: Ident,
+    len: usize,
+}
+
+/// Find the index of `v-once` attribute of the element.
+pub(crate) fn find_v_once(jsx_element: &JSXElement) -> Option<usize> {
+    find_attr(jsx_element, "v-once", "vOnce")
+}
+
+/// Find the index of `v-memo` attribute of the element.
+pub(crate) fn find_v_memo(jsx_element: &JSXElement) -> Option<usize> {
+    find_attr(jsx_element, "v-memo", "vMemo")
+}
+
+fn find_attr(jsx_element: &JSXElement, name: &str, camel_case_name: &str) -> Option<usize> {
+    jsx_element
+        .opening
+        .attrs
+        .iter()
+        .position(|jsx_attr_or_spread| {
+            matches!(
+                jsx_attr_or_spread,
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(ident),
+                    ..
+                }) if ident.sym == name || ident.sym == camel_case_name
+            )
+        })
+}
+
+impl<C> VueJsxTransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Add `_ctx` and `_cache` parameters to the render function returned from `setup`,
+    /// so cached vnodes are stored per component instance.
+    /// Parameters will be removed if nothing is cached.
+    pub(crate) fn prepare_render_cache(&mut self, call_expr: &mut CallExpr) {
+        let Some(render) = util::find_setup_render(call_expr) else {
+            return;
+        };
+        if !render.params.is_empty() || render.is_async || render.is_generator {
+            return;
+        }
+        let cache = private_ident!("_cache");
+        render.params = vec![
+            Pat::Ident(private_ident!("_ctx").into()),
+            Pat::Ident(cache.clone().into()),
+        ];
+        self.render_cache_idents.push(cache);
+    }
+
+    /// Start caching if the arrow function is a render function prepared before.
+    /// The cache of the outer render function is returned, so it can be restored later.
+    pub(crate) fn enter_render_fn(
+        &mut self,
+        arrow_expr: &ArrowExpr,
+    ) -> Option<Option<RenderCache>> {
+        let Some(Pat::Ident(BindingIdent { id, .. })) = arrow_expr.params.get(1) else {
+            return None;
+        };
+        let position = self
+            .render_cache_idents
+            .iter()
+            .position(|ident| ident.to_id() == id.to_id())?;
+        let ident = self.render_cache_idents.remove(position);
+        Some(self.render_cache.replace(RenderCache { ident, len: 0 }))
+    }
+
+    pub(crate) fn leave_render_fn(
+        &mut self,
+        arrow_expr: &mut ArrowExpr,
+        outer: Option<RenderCache>,
+    ) {
+        if let Some(RenderCache { len: 0, .. }) = mem::replace(&mut self.render_cache, outer) {
+            arrow_expr.params.clear();
+        }
+    }
+
+    /// Compile `v-once` to a vnode which is created once and stored in render cache.
+    /// Block tracking is paused while creating it,
+    /// so it won't be collected as dynamic children of the parent block.
+    /// Key of the fragment is given when it's a list rendered by `v-for` in a branch of `v-if`.
+    pub(crate) fn transform_v_once(
+        &mut self,
+        jsx_element: &JSXElement,
+        index: usize,
+        block_type: BlockType,
+        fragment_key: Option<usize>,
+    ) -> Expr {
+        let mut jsx_element = jsx_element.clone();
+        let jsx_attr = jsx_element.opening.attrs.remove(index);
+        let Some((cache, index)) = self.alloc_render_cache() else {
+            if !self.options.ssr {
+                Diagnostic::CacheOutsideRender.emit(jsx_attr.span(), &self.options);
+            }
+            return self.transform_cached_element(&jsx_element, block_type, fragment_key);
+        };
+
+        let vnode = self.transform_cached_element(&jsx_element, BlockType::None, fragment_key);
+        let set_block_tracking = self.import_from_vue("setBlockTracking");
+        let build_set_block_tracking = |value: Expr| {
+            Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(set_block_tracking.clone()))),
+                args: vec![ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(value),
+                }],
+                type_args: None,
+            }))
+        };
+
+        Expr::Bin(BinExpr {
+            span: jsx_element.span,
+            op: op!("||"),
+            left: Box::new(Expr::Member(build_cache_member(&cache, index))),
+            right: Box::new(Expr::Paren(ParenExpr {
+                span: jsx_element.span,
+                expr: Box::new(Expr::Seq(SeqExpr {
+                    span: jsx_element.span,
+                    exprs: vec![
+                        build_set_block_tracking(Expr::Unary(UnaryExpr {
+                            span: DUMMY_SP,
+                            op: op!(unary, "-"),
+                            arg: Box::new(Expr::Lit(Lit::Num(1.0.into()))),
+                        })),
+                        Box::new(Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            op: op!("="),
+                            left: AssignTarget::Simple(SimpleAssignTarget::Member(
+                                build_cache_member(&cache, index),
+                            )),
+                            right: Box::new(vnode),
+                        })),
+                        build_set_block_tracking(Expr::Lit(Lit::Num(1.0.into()))),
+                        Box::new(Expr::Member(build_cache_member(&cache, index))),
+                    ],
+                })),
+            })),
+        })
+    }
+
+    fn transform_cached_element(
+        &mut self,
+        jsx_element: &JSXElement,
+        block_type: BlockType,
+        fragment_key: Option<usize>,
+    ) -> Expr {
+        match v_for::find_v_for(jsx_element) {
+            Some(index) if fragment_key.is_some() => {
+                self.transform_v_for(jsx_element, index, fragment_key)
+            }
+            _ => self.transform_jsx_element(jsx_element, block_type),
+        }
+    }
+
+    /// Compile `v-memo` to `withMemo`, which reuses the cached vnode until dependencies change.
+    pub(crate) fn transform_v_memo(
+        &mut self,
+        jsx_element: &JSXElement,
+        block_type: BlockType,
+    ) -> Expr {
+        let mut jsx_element = jsx_element.clone();
+        let Some((deps, cache, index)) = self.take_v_memo(&mut jsx_element) else {
+            return self.transform_jsx_element(&jsx_element, block_type);
+        };
+
+        // cached vnode is pushed to the parent block by `withMemo` and patched as a whole,
+        // so stable element is a block tracking its own dynamic descendants,
+        // while unstable element is a plain vnode whose children are diffed fully,
+        // since a block would skip children which aren't tracked
+        let use_block =
+            self.options.optimize && self.pragma.is_none() && self.options.pragma.is_none();
+        let vnode = if use_block && self.is_stable_element(&jsx_element) {
+            // static element can't be hoisted, otherwise it won't be a block
+            let hoisting = mem::replace(&mut self.hoisting, true);
+            let vnode = self.transform_jsx_element(&jsx_element, BlockType::Stable);
+            self.hoisting = hoisting;
+            vnode
+        } else {
+            self.transform_jsx_element(&jsx_element, BlockType::None)
+        };
+
+        Expr::Call(CallExpr {
+            span: jsx_element.span,
+            callee: Callee::Expr(Box::new(Expr::Ident(self.import_from_vue("withMemo")))),
+            args: vec![
+                ExprOrSpread {
+                    spread: None,
+                    expr: deps,
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Arrow(ArrowExpr {
+                        span: DUMMY_SP,
+                        params: vec![],
+                        body: Box::new(BlockStmtOrExpr::Expr(Box::new(vnode))),
+                        is_async: false,
+                        is_generator: false,
+                        type_params: None,
+                        return_type: None,
+                    })),
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Ident(cache)),
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Num((index as f64).into()))),
+                },
+            ],
+            type_args: None,
+        })
+    }
+
+    /// Remove `v-memo` attribute from the element,
+    /// then return its dependencies, the render cache and the allocated cache index.
+    pub(crate) fn take_v_memo(
+        &mut self,
+        jsx_element: &mut JSXElement,
+    ) -> Option<(Box<Expr>, Ident, usize)> {
+        let index = find_v_memo(jsx_element)?;
+        let JSXAttrOrSpread::JSXAttr(jsx_attr) = jsx_element.opening.attrs.remove(index) else {
+            unreachable!()
+        };
+        let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(deps),
+            ..
+        })) = jsx_attr.value
+        else {
+            Diagnostic::VMemoWithoutExpr.emit(jsx_attr.span, &self.options);
+            return None;
+        };
+        let Some((cache, index)) = self.alloc_render_cache() else {
+            if !self.options.ssr {
+                Diagnostic::CacheOutsideRender.emit(jsx_attr.span, &self.options);
+            }
+            return None;
+        };
+        Some((deps, cache, index))
+    }
+
+    /// Build callback of `renderList` which reuses the cached item
+    /// if its key and memo dependencies are unchanged.
+    pub(crate) fn build_memo_render_item(
+        &mut self,
+        mut params: Vec<Pat>,
+        item: Expr,
+        key: Option<Box<Expr>>,
+        deps: Expr,
+    ) -> ArrowExpr {
+        // cached item is passed as the fourth argument, after value, key and index
+        params.truncate(3);
+        while params.len() < 3 {
+            let placeholder = private_ident!("_".repeat(params.len() + 1));
+            params.push(Pat::Ident(placeholder.into()));
+        }
+        let cached = private_ident!("_cached");
+        params.push(Pat::Ident(cached.clone().into()));
+        let memo = private_ident!("_memo");
+        let item_ident = private_ident!("_item");
+
+        let mut test = Expr::Ident(cached.clone());
+        if let Some(key) = key {
+            test = Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                op: op!("&&"),
+                left: Box::new(test),
+                right: Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: op!("==="),
+                    left: Box::new(Expr::Member(MemberExpr {
+                        span: DUMMY_SP,
+                        obj: Box::new(Expr::Ident(cached.clone())),
+                        prop: MemberProp::Ident(quote_ident!("key")),
+                    })),
+                    right: key,
+                })),
+            });
+        }
+        test = Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: op!("&&"),
+            left: Box::new(test),
+            right: Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(self.import_from_vue("isMemoSame")))),
+                args: vec![
+                    ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::Ident(cached.clone())),
+                    },
+                    ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::Ident(memo.clone())),
+                    },
+                ],
+                type_args: None,
+            })),
+        });
+
+        let stmts = vec![
+            build_const(memo.clone(), deps),
+            Stmt::If(IfStmt {
+                span: DUMMY_SP,
+                test: Box::new(test),
+                cons: Box::new(Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(Box::new(Expr::Ident(cached))),
+                })),
+                alt: None,
+            }),
+            build_const(item_ident.clone(), item),
+            Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+                        span: DUMMY_SP,
+                        obj: Box::new(Expr::Ident(item_ident.clone())),
+                        prop: MemberProp::Ident(quote_ident!("memo")),
+                    })),
+                    right: Box::new(Expr::Ident(memo)),
+                })),
+            }),
+            Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(Box::new(Expr::Ident(item_ident))),
+            }),
+        ];
+
+        ArrowExpr {
+            span: DUMMY_SP,
+            params,
+            body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                span: DUMMY_SP,
+                stmts,
+            })),
+            is_async: false,
+            is_generator: false,
+            type_params: None,
+            return_type: None,
+        }
+    }
+
+    /// Allocate a slot of the render cache of the current component.
+    fn alloc_render_cache(&mut self) -> Option<(Ident, usize)> {
+        if self.options.ssr {
+            return None;
+        }
+        let render_cache = self.render_cache.as_mut()?;
+        let index = render_cache.len;
+        render_cache.len += 1;
+        Some((render_cache.ident.clone(), index))
+    }
+}
+
+fn build_cache_member(cache: &Ident, index: usize) -> MemberExpr {
+    MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(Expr::Ident(cache.clone())),
+        prop: MemberProp::Computed(ComputedPropName {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Lit(Lit::Num((index as f64).into()))),
+        }),
+    }
+}
+
+fn build_const(name: Ident, init: Expr) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(name.into()),
+            init: Some(Box::new(init)),
+            definite: false,
+        }],
+    })))
+}
@@ -1,14 +1,20 @@
-use crate::VueJsxTransformVisitor;
+use crate::{diagnostics::Diagnostic, FsTypeResolver, VueJsxTransformVisitor};
+use fnv::{FnvHashMap, FnvHashSet};
 use indexmap::{IndexMap, IndexSet};
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 use swc_core::{
-    common::{comments::Comments, EqIgnoreSpan, Span, Spanned, DUMMY_SP},
+    common::{comments::Comments, EqIgnoreSpan, Mark, Span, Spanned, SyntaxContext, DUMMY_SP},
     ecma::{
         ast::*,
         atoms::{js_word, JsWord},
+        transforms::base::resolver,
         utils::{quote_ident, quote_str},
+        visit::{noop_visit_mut_type, Visit, VisitMut, VisitMutWith, VisitWith},
     },
-    plugin::errors::HANDLER,
 };
 
 enum RefinedTsTypeElement {
@@ -23,13 +29,62 @@ struct PropIr {
     required: bool,
 }
 
+pub(crate) struct TypeImport {
+    importer: PathBuf,
+    src: Str,
+    imported: JsWord,
+    type_only: bool,
+}
+
+/// Collect names of types referenced by type annotations.
+#[derive(Default)]
+pub(crate) struct TypeRefCollector(Vec<(JsWord, SyntaxContext)>);
+
+impl TypeRefCollector {
+    fn collect<N>(node: &N) -> Vec<(JsWord, SyntaxContext)>
+    where
+        N: VisitWith<Self>,
+    {
+        let mut collector = Self::default();
+        node.visit_with(&mut collector);
+        collector.0
+    }
+}
+
+impl Visit for TypeRefCollector {
+    fn visit_ts_entity_name(&mut self, ts_entity_name: &TsEntityName) {
+        match ts_entity_name {
+            TsEntityName::Ident(ident) => self.0.push((ident.sym.clone(), ident.span.ctxt())),
+            TsEntityName::TsQualifiedName(ts_qualified_name) => {
+                ts_qualified_name.left.visit_with(self)
+            }
+        }
+    }
+
+    fn visit_ts_expr_with_type_args(&mut self, ts_expr_with_type_args: &TsExprWithTypeArgs) {
+        if let Expr::Ident(ident) = &*ts_expr_with_type_args.expr {
+            self.0.push((ident.sym.clone(), ident.span.ctxt()));
+        }
+        ts_expr_with_type_args.type_args.visit_with(self);
+    }
+}
+
+pub(crate) struct EmitIr {
+    name: JsWord,
+    validator: Option<Expr>,
+}
+
 impl<C> VueJsxTransformVisitor<C>
 where
     C: Comments,
 {
-    pub(crate) fn extract_props_type(&mut self, setup_fn: &ExprOrSpread) -> Option<Expr> {
+    pub(crate) fn extract_props_type(
+        &mut self,
+        setup_fn: &ExprOrSpread,
+        type_args: Option<&TsTypeParamInstantiation>,
+    ) -> Option<Expr> {
         let mut defaults = None;
-        let Some(first_param_type) = (if let ExprOrSpread { expr, spread: None } = setup_fn {
+        let first_param_type = if let ExprOrSpread { expr, spread: None } = setup_fn {
             match &**expr {
                 Expr::Arrow(arrow) => arrow.params.first().and_then(|param| {
                     if let Pat::Assign(AssignPat { right, .. }) = param {
@@ -47,9 +102,12 @@ where
             }
         } else {
             None
-        }) else {
-            return None;
         };
+        // props given by `defineComponent<Props>` take precedence over type of the first parameter
+        let props_type = type_args
+            .and_then(|type_args| type_args.params.first())
+            .map(|props_type| &**props_type)
+            .or(first_param_type.map(|type_ann| &*type_ann.type_ann))?;
 
         enum Defaults<'n> {
             Static(Vec<(Cow<'n, PropName>, Expr)>),
@@ -77,9 +135,7 @@ where
                                     }),
                                 )),
                                 Prop::KeyValue(KeyValueProp { key, value }) => {
-                                    let Some(key) = try_unwrap_lit_prop_name(key) else {
-                                        return None;
-                                    };
+                                    let key = try_unwrap_lit_prop_name(key)?;
                                     Some((
                                         key,
                                         if value.is_lit() {
@@ -104,9 +160,7 @@ where
                                     body: Some(body),
                                     ..
                                 }) => {
-                                    let Some(key) = try_unwrap_lit_prop_name(key) else {
-                                        return None;
-                                    };
+                                    let key = try_unwrap_lit_prop_name(key)?;
                                     Some((
                                         key,
                                         Expr::Arrow(ArrowExpr {
@@ -123,9 +177,7 @@ where
                                     ))
                                 }
                                 Prop::Method(MethodProp { key, function }) => {
-                                    let Some(key) = try_unwrap_lit_prop_name(key) else {
-                                        return None;
-                                    };
+                                    let key = try_unwrap_lit_prop_name(key)?;
                                     Some((
                                         key,
                                         Expr::Fn(FnExpr {
@@ -153,7 +205,7 @@ where
 
         Some(match defaults {
             Some(Defaults::Static(props)) => {
-                Expr::Object(self.build_props_type(first_param_type, Some(props)))
+                Expr::Object(self.build_props_type(props_type, Some(props)))
             }
             Some(Defaults::Dynamic(expr)) => {
                 let merge_defaults = self.import_from_vue("mergeDefaults");
@@ -161,9 +213,7 @@ where
                     callee: Callee::Expr(Box::new(Expr::Ident(merge_defaults))),
                     args: vec![
                         ExprOrSpread {
-                            expr: Box::new(Expr::Object(
-                                self.build_props_type(first_param_type, None),
-                            )),
+                            expr: Box::new(Expr::Object(self.build_props_type(props_type, None))),
                             spread: None,
                         },
                         ExprOrSpread {
@@ -181,13 +231,13 @@ where
                     },
                 })
             }
-            None => Expr::Object(self.build_props_type(first_param_type, None)),
+            None => Expr::Object(self.build_props_type(props_type, None)),
         })
     }
 
     fn build_props_type(
         &self,
-        TsTypeAnn { type_ann, .. }: &TsTypeAnn,
+        type_ann: &TsType,
         defaults: Option<Vec<(Cow<PropName>, Expr)>>,
     ) -> ObjectLit {
         let mut props = Vec::with_capacity(3);
@@ -212,7 +262,7 @@ where
                         type_ann,
                         ..
                     }) => {
-                        let prop_name = extract_prop_name(*key, computed);
+                        let prop_name = self.extract_prop_name(*key, computed);
                         let types = if let Some(type_ann) = type_ann {
                             self.infer_runtime_type(&type_ann.type_ann)
                         } else {
@@ -244,7 +294,7 @@ where
                         optional,
                         ..
                     }) => {
-                        let prop_name = extract_prop_name(*key, computed);
+                        let prop_name = self.extract_prop_name(*key, computed);
                         let ty = Some(js_word!("Function"));
                         if let Some((_, ir)) = irs
                             .iter_mut()
@@ -529,18 +579,11 @@ where
                             }
                         }
                         _ => {
-                            HANDLER.with(|handler| {
-                                handler.span_err(
-                                    *span,
-                                    "Unresolvable type reference or unsupported built-in utility type.",
-                                );
-                            });
+                            Diagnostic::UnresolvableTypeReference.emit(*span, &self.options);
                         }
                     }
                 } else {
-                    HANDLER.with(|handler| {
-                        handler.span_err(*span, "Types from other modules can't be resolved.");
-                    });
+                    Diagnostic::UnresolvableImportedType.emit(*span, &self.options);
                 }
             }
             TsType::TsIndexedAccessType(TsIndexedAccessType {
@@ -551,9 +594,7 @@ where
                 if let Some(ty) = self.resolve_indexed_access(obj_type, index_type) {
                     self.resolve_type_elements(&ty, props);
                 } else {
-                    HANDLER.with(|handler| {
-                        handler.span_err(ty.span(), "Unresolvable type.");
-                    });
+                    Diagnostic::UnresolvableType.emit(ty.span(), &self.options);
                 }
             }
             TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(TsFnType {
@@ -573,9 +614,7 @@ where
             | TsType::TsOptionalType(TsOptionalType { type_ann, .. }) => {
                 self.resolve_type_elements(type_ann, props);
             }
-            _ => HANDLER.with(|handler| {
-                handler.span_err(ty.span(), "Unresolvable type.");
-            }),
+            _ => Diagnostic::UnresolvableType.emit(ty.span(), &self.options),
         }
     }
 
@@ -611,23 +650,15 @@ where
                 {
                     self.resolve_string_or_union_strings(aliased)
                 } else if ident.span.ctxt().has_mark(self.unresolved_mark) {
-                    HANDLER.with(|handler| {
-                        handler.span_err(
-                            ty.span(),
-                            "Unresolvable type reference or unsupported built-in utility type.",
-                        );
-                    });
+                    Diagnostic::UnresolvableTypeReference.emit(ty.span(), &self.options);
                     vec![]
                 } else {
-                    HANDLER.with(|handler| {
-                        handler.span_err(ty.span(), "Types from other modules can't be resolved.");
-                    });
+                    Diagnostic::UnresolvableImportedType.emit(ty.span(), &self.options);
                     vec![]
                 }
             }
             _ => {
-                HANDLER
-                    .with(|handler| handler.span_err(ty.span(), "Unsupported type as index key."));
+                Diagnostic::UnsupportedIndexKey.emit(ty.span(), &self.options);
                 vec![]
             }
         }
@@ -1092,113 +1123,605 @@ where
         runtime_types
     }
 
-    pub(crate) fn extract_emits_type(&self, setup_fn: &ExprOrSpread) -> Option<ArrayLit> {
-        let Some(TsTypeAnn {
-            type_ann: second_param_type,
-            ..
-        }) = (if let ExprOrSpread { expr, spread: None } = setup_fn {
-            match &**expr {
-                Expr::Arrow(arrow) => match arrow.params.get(1) {
-                    Some(Pat::Ident(ident)) => ident.type_ann.as_deref(),
-                    Some(Pat::Array(array)) => array.type_ann.as_deref(),
-                    Some(Pat::Object(object)) => object.type_ann.as_deref(),
-                    _ => return None,
-                },
-                Expr::Fn(fn_expr) => match fn_expr.function.params.get(1).map(|param| &param.pat) {
-                    Some(Pat::Ident(ident)) => ident.type_ann.as_deref(),
-                    Some(Pat::Array(array)) => array.type_ann.as_deref(),
-                    Some(Pat::Object(object)) => object.type_ann.as_deref(),
-                    _ => return None,
-                },
-                _ => return None,
-            }
-        } else {
-            return None;
-        })
-        else {
-            return None;
-        };
-
-        match &**second_param_type {
+    /// Check if runtime types inferred from the given type are exactly what it can be at runtime,
+    /// rather than falling back to `Object` for types which can't be inferred,
+    /// like enums, generic parameters, `keyof` and conditional types.
+    /// `Object` type itself isn't precise either, since primitives are assignable to it.
+    fn is_precise_runtime_type(&self, ty: &TsType) -> bool {
+        match ty {
+            TsType::TsKeywordType(..)
+            | TsType::TsTypeLit(..)
+            | TsType::TsFnOrConstructorType(..)
+            | TsType::TsArrayType(..)
+            | TsType::TsTupleType(..) => true,
+            // bigint literals are inferred as `Number`
+            TsType::TsLitType(TsLitType { lit, .. }) => !matches!(lit, TsLit::BigInt(..)),
             TsType::TsTypeRef(TsTypeRef {
                 type_name: TsEntityName::Ident(ident),
-                type_params: Some(type_params),
+                type_params,
                 ..
-            }) if ident.sym == "SetupContext" => {
-                if let Some(emits_def) = type_params.params.first() {
-                    let mut emits = Vec::with_capacity(1);
-                    self.resolve_type_elements(emits_def, &mut emits);
-                    Some(ArrayLit {
-                        elems: emits
-                            .into_iter()
-                            .flat_map(|emit| match emit {
-                                RefinedTsTypeElement::MethodSignature(TsMethodSignature {
-                                    key,
-                                    ..
+            }) => {
+                let key = (ident.sym.clone(), ident.span.ctxt());
+                if let Some(aliased) = self.type_aliases.get(&key) {
+                    return self.is_precise_runtime_type(aliased);
+                }
+                if self.interfaces.contains_key(&key) {
+                    return true;
+                }
+                let type_param = |index: usize| {
+                    type_params
+                        .as_ref()
+                        .and_then(|type_params| type_params.params.get(index))
+                        .is_some_and(|ty| self.is_precise_runtime_type(ty))
+                };
+                match &*ident.sym {
+                    "Array"
+                    | "Function"
+                    | "Set"
+                    | "Map"
+                    | "WeakSet"
+                    | "WeakMap"
+                    | "Date"
+                    | "Promise"
+                    | "Error"
+                    | "RegExp"
+                    | "Record"
+                    | "Pick"
+                    | "Omit"
+                    | "InstanceType"
+                    | "Uppercase"
+                    | "Lowercase"
+                    | "Capitalize"
+                    | "Uncapitalize"
+                    | "Parameters"
+                    | "ConstructorParameters" => ident.span.ctxt().has_mark(self.unresolved_mark),
+                    "Partial" | "Required" | "Readonly" | "NonNullable" | "Exclude"
+                    | "OmitThisParameter" => type_param(0),
+                    "Extract" => type_param(1),
+                    _ => false,
+                }
+            }
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. })
+            | TsType::TsOptionalType(TsOptionalType { type_ann, .. }) => {
+                self.is_precise_runtime_type(type_ann)
+            }
+            TsType::TsUnionOrIntersectionType(
+                TsUnionOrIntersectionType::TsUnionType(TsUnionType { types, .. })
+                | TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType { types, .. }),
+            ) => types.iter().all(|ty| self.is_precise_runtime_type(ty)),
+            TsType::TsIndexedAccessType(TsIndexedAccessType {
+                obj_type,
+                index_type,
+                ..
+            }) => self
+                .resolve_indexed_access(obj_type, index_type)
+                .is_some_and(|ty| self.is_precise_runtime_type(&ty)),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn extract_emits_type(
+        &self,
+        setup_fn: &ExprOrSpread,
+        type_args: Option<&TsTypeParamInstantiation>,
+    ) -> Option<Vec<EmitIr>> {
+        // emits given by `defineComponent<Props, Emits>` take precedence over `SetupContext<Emits>`
+        let emits_def = match type_args.and_then(|type_args| type_args.params.get(1)) {
+            Some(emits_def) => emits_def,
+            None => extract_setup_context_emits(setup_fn)?,
+        };
+        let mut emits = Vec::with_capacity(1);
+        self.resolve_type_elements(emits_def, &mut emits);
+        Some(merge_emit_overloads(
+            emits
+                .into_iter()
+                .flat_map(|emit| {
+                    let (names, payload) = match emit {
+                        RefinedTsTypeElement::MethodSignature(TsMethodSignature {
+                            key,
+                            params,
+                            ..
+                        }) => (extract_emit_name(&key), params),
+                        RefinedTsTypeElement::Property(TsPropertySignature {
+                            key,
+                            type_ann,
+                            ..
+                        }) => (
+                            extract_emit_name(&key),
+                            match type_ann.map(|type_ann| *type_ann.type_ann) {
+                                Some(TsType::TsFnOrConstructorType(
+                                    TsFnOrConstructorType::TsFnType(TsFnType { params, .. }),
+                                )) => params,
+                                _ => vec![],
+                            },
+                        ),
+                        RefinedTsTypeElement::CallSignature(TsCallSignatureDecl {
+                            mut params,
+                            ..
+                        }) => {
+                            let names = params
+                                .first()
+                                .and_then(|param| match param {
+                                    TsFnParam::Ident(ident) => ident.type_ann.as_deref(),
+                                    TsFnParam::Array(array) => array.type_ann.as_deref(),
+                                    TsFnParam::Rest(rest) => rest.type_ann.as_deref(),
+                                    TsFnParam::Object(object) => object.type_ann.as_deref(),
                                 })
-                                | RefinedTsTypeElement::Property(TsPropertySignature {
-                                    key, ..
-                                }) => match &*key {
-                                    Expr::Ident(ident) => vec![ident.sym.clone()],
-                                    Expr::Lit(Lit::Str(str)) => vec![str.value.clone()],
-                                    _ => vec![],
-                                },
-                                RefinedTsTypeElement::CallSignature(TsCallSignatureDecl {
-                                    params,
-                                    ..
-                                }) => params
-                                    .first()
-                                    .and_then(|param| match param {
-                                        TsFnParam::Ident(ident) => ident.type_ann.as_deref(),
-                                        TsFnParam::Array(array) => array.type_ann.as_deref(),
-                                        TsFnParam::Rest(rest) => rest.type_ann.as_deref(),
-                                        TsFnParam::Object(object) => object.type_ann.as_deref(),
-                                    })
-                                    .map(|type_ann| {
-                                        self.resolve_string_or_union_strings(&type_ann.type_ann)
-                                    })
-                                    .unwrap_or_default(),
-                                RefinedTsTypeElement::GetterSignature(..) => vec![],
-                            })
-                            .map(|name| {
-                                Some(ExprOrSpread {
-                                    expr: Box::new(Expr::Lit(Lit::Str(quote_str!(name)))),
-                                    spread: None,
+                                .map(|type_ann| {
+                                    self.resolve_string_or_union_strings(&type_ann.type_ann)
                                 })
+                                .unwrap_or_default();
+                            if !params.is_empty() {
+                                params.remove(0);
+                            }
+                            (names, params)
+                        }
+                        RefinedTsTypeElement::GetterSignature(..) => (vec![], vec![]),
+                    };
+                    let validator = if self.options.validate_emits {
+                        self.build_emit_validator(&payload)
+                    } else {
+                        None
+                    };
+                    names.into_iter().map(move |name| EmitIr {
+                        name,
+                        validator: validator.clone(),
+                    })
+                })
+                .collect(),
+        ))
+    }
+
+    /// Build validator of emit payload like `(value) => typeof value === "string"`.
+    /// `None` is returned if none of parameters can be checked at runtime,
+    /// or any of them can't be checked exactly.
+    fn build_emit_validator(&self, params: &[TsFnParam]) -> Option<Expr> {
+        let mut arrow_params = Vec::with_capacity(params.len());
+        let mut checks = vec![];
+        for (index, param) in params.iter().enumerate() {
+            let (ident, type_ann) = match param {
+                TsFnParam::Ident(BindingIdent { id, type_ann }) => {
+                    (quote_ident!(id.sym.clone()), type_ann.as_deref())
+                }
+                TsFnParam::Array(ArrayPat { type_ann, .. })
+                | TsFnParam::Object(ObjectPat { type_ann, .. }) => {
+                    (quote_ident!(format!("arg{index}")), type_ann.as_deref())
+                }
+                // rest parameters are left unchecked
+                TsFnParam::Rest(..) => break,
+            };
+            // checking types which aren't known exactly may reject valid payloads
+            if type_ann.is_some_and(|type_ann| !self.is_precise_runtime_type(&type_ann.type_ann)) {
+                return None;
+            }
+            // `null` in union is checked separately,
+            // since it can't be told apart from types which can't be checked
+            let (nullable, types) = match type_ann.map(|type_ann| &*type_ann.type_ann) {
+                Some(TsType::TsUnionOrIntersectionType(
+                    TsUnionOrIntersectionType::TsUnionType(TsUnionType { types, .. }),
+                )) => {
+                    let (nulls, types): (Vec<_>, Vec<_>) = types.iter().partition(|ty| {
+                        matches!(
+                            &***ty,
+                            TsType::TsKeywordType(TsKeywordType {
+                                kind: TsKeywordTypeKind::TsNullKeyword,
+                                ..
                             })
+                        )
+                    });
+                    (
+                        !nulls.is_empty(),
+                        types
+                            .into_iter()
+                            .flat_map(|ty| self.infer_runtime_type(ty))
                             .collect(),
-                        span: DUMMY_SP,
+                    )
+                }
+                Some(ty) => (false, self.infer_runtime_type(ty)),
+                None => (false, IndexSet::new()),
+            };
+            // `any` and `unknown` can't be checked
+            if !types.is_empty() && types.iter().all(Option::is_some) {
+                let is_optional =
+                    matches!(param, TsFnParam::Ident(BindingIdent { id, .. }) if id.optional);
+                let check = types
+                    .into_iter()
+                    .flatten()
+                    .map(|ty| build_runtime_type_check(&ident, &ty))
+                    .chain(nullable.then(|| {
+                        build_bin_expr(
+                            op!("==="),
+                            Expr::Ident(ident.clone()),
+                            Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+                        )
+                    }))
+                    .chain(is_optional.then(|| {
+                        build_bin_expr(
+                            op!("==="),
+                            Expr::Ident(ident.clone()),
+                            Expr::Ident(quote_ident!("undefined")),
+                        )
+                    }))
+                    .reduce(|left, right| build_bin_expr(op!("||"), left, right));
+                checks.extend(check);
+            }
+            arrow_params.push(Pat::Ident(ident.into()));
+        }
+
+        let check = checks
+            .into_iter()
+            .reduce(|left, right| build_bin_expr(op!("&&"), left, right))?;
+        Some(Expr::Arrow(ArrowExpr {
+            params: arrow_params,
+            body: Box::new(BlockStmtOrExpr::Expr(Box::new(check))),
+            is_async: false,
+            is_generator: false,
+            type_params: None,
+            return_type: None,
+            span: DUMMY_SP,
+        }))
+    }
+
+    /// Build the `emits` option, which is an object with validators if `validateEmits` is enabled,
+    /// or an array of event names.
+    pub(crate) fn build_emits_option(&self, emits: Vec<EmitIr>) -> Expr {
+        if self.options.validate_emits {
+            Expr::Object(ObjectLit {
+                props: emits
+                    .into_iter()
+                    .map(|EmitIr { name, validator }| {
+                        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: if Ident::verify_symbol(&name).is_ok() {
+                                PropName::Ident(quote_ident!(name))
+                            } else {
+                                PropName::Str(quote_str!(name))
+                            },
+                            value: Box::new(
+                                validator.unwrap_or(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+                            ),
+                        })))
                     })
-                } else {
-                    None
+                    .collect(),
+                span: DUMMY_SP,
+            })
+        } else {
+            Expr::Array(ArrayLit {
+                elems: emits
+                    .into_iter()
+                    .map(|EmitIr { name, .. }| {
+                        Some(ExprOrSpread {
+                            expr: Box::new(Expr::Lit(Lit::Str(quote_str!(name)))),
+                            spread: None,
+                        })
+                    })
+                    .collect(),
+                span: DUMMY_SP,
+            })
+        }
+    }
+
+    /// Props like `modelValue` along with `onUpdate:modelValue` prop or `update:modelValue` emit
+    /// are regarded as models, which are bound by `v-model`,
+    /// so props of modifiers and `update:` emits are added if they're missing.
+    pub(crate) fn inject_models(&self, props: Option<&mut Expr>, emits: &mut Option<Vec<EmitIr>>) {
+        let props = match props {
+            Some(Expr::Object(object)) => object,
+            // props with defaults are wrapped by `mergeDefaults`
+            Some(Expr::Call(CallExpr { args, .. })) => match args.first_mut() {
+                Some(ExprOrSpread { expr, spread: None }) => match &mut **expr {
+                    Expr::Object(object) => object,
+                    _ => return,
+                },
+                _ => return,
+            },
+            _ => return,
+        };
+
+        let prop_names = props
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(Ident { sym, .. }),
+                        ..
+                    })
+                    | Prop::KeyValue(KeyValueProp {
+                        key: PropName::Str(Str { value: sym, .. }),
+                        ..
+                    }) => Some(sym.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let emit_names = emits
+            .iter()
+            .flatten()
+            .map(|emit| emit.name.clone())
+            .collect::<Vec<_>>();
+
+        for name in &prop_names {
+            if name.starts_with("onUpdate:") {
+                continue;
+            }
+            let event = format!("update:{name}");
+            let handler = format!("onUpdate:{name}");
+            let has_emit = emit_names.iter().any(|emit| *emit == *event);
+            if !has_emit && !prop_names.iter().any(|prop| *prop == *handler) {
+                continue;
+            }
+
+            let modifiers = if name == "modelValue" {
+                "modelModifiers".to_string()
+            } else {
+                format!("{name}Modifiers")
+            };
+            if !prop_names.iter().any(|prop| *prop == *modifiers) {
+                props
+                    .props
+                    .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: if Ident::verify_symbol(&modifiers).is_ok() {
+                            PropName::Ident(quote_ident!(modifiers))
+                        } else {
+                            PropName::Str(quote_str!(modifiers))
+                        },
+                        value: Box::new(Expr::Object(ObjectLit {
+                            props: vec![
+                                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                    key: PropName::Ident(quote_ident!("type")),
+                                    value: Box::new(Expr::Ident(quote_ident!("Object"))),
+                                }))),
+                                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                    key: PropName::Ident(quote_ident!("required")),
+                                    value: Box::new(Expr::Lit(Lit::Bool(Bool {
+                                        value: false,
+                                        span: DUMMY_SP,
+                                    }))),
+                                }))),
+                            ],
+                            span: DUMMY_SP,
+                        })),
+                    }))));
+            }
+            if !has_emit {
+                emits.get_or_insert_with(Vec::new).push(EmitIr {
+                    name: event.into(),
+                    validator: None,
+                });
+            }
+        }
+    }
+
+    pub(crate) fn collect_ts_interface(&mut self, ts_interface_decl: &TsInterfaceDecl) {
+        let key = (
+            ts_interface_decl.id.sym.clone(),
+            ts_interface_decl.id.span.ctxt(),
+        );
+        if let Some(interface) = self.interfaces.get_mut(&key) {
+            interface
+                .body
+                .body
+                .extend_from_slice(&ts_interface_decl.body.body);
+        } else {
+            self.interfaces.insert(key, ts_interface_decl.clone());
+        }
+    }
+
+    pub(crate) fn collect_ts_type_alias(&mut self, ts_type_alias_decl: &TsTypeAliasDecl) {
+        self.type_aliases.insert(
+            (
+                ts_type_alias_decl.id.sym.clone(),
+                ts_type_alias_decl.id.span.ctxt(),
+            ),
+            (*ts_type_alias_decl.type_ann).clone(),
+        );
+    }
+
+    pub(crate) fn create_type_resolver(&self) -> FsTypeResolver {
+        if let Some(tsconfig) = &self.options.tsconfig {
+            FsTypeResolver::from_tsconfig(tsconfig).unwrap_or_else(|err| {
+                Diagnostic::FailedToReadTsconfig {
+                    path: tsconfig,
+                    reason: err.to_string(),
                 }
+                .emit(DUMMY_SP, &self.options);
+                FsTypeResolver::new()
+            })
+        } else {
+            FsTypeResolver::new()
+        }
+    }
+
+    /// Register named imports of types, which are loaded only when they're referenced
+    /// by types of props or emits, so unused types from unresolvable packages are ignored.
+    pub(crate) fn register_imported_types(&mut self, importer: &Path, import_decl: &ImportDecl) {
+        for specifier in &import_decl.specifiers {
+            let ImportSpecifier::Named(ImportNamedSpecifier {
+                local,
+                imported,
+                is_type_only,
+                ..
+            }) = specifier
+            else {
+                continue;
+            };
+            self.type_imports.insert(
+                (local.sym.clone(), local.span.ctxt()),
+                TypeImport {
+                    importer: importer.to_path_buf(),
+                    src: (*import_decl.src).clone(),
+                    imported: imported
+                        .as_ref()
+                        .map_or(&local.sym, module_export_name_sym)
+                        .clone(),
+                    type_only: import_decl.type_only || *is_type_only,
+                },
+            );
+        }
+    }
+
+    /// Load imported types referenced by the given types, including those referenced indirectly,
+    /// as aliases of types declared in the imported module,
+    /// so they can be resolved as same as local types.
+    pub(crate) fn load_referenced_types<N>(&mut self, node: &N)
+    where
+        N: VisitWith<TypeRefCollector>,
+    {
+        let mut refs = TypeRefCollector::collect(node);
+        let mut visited = FnvHashSet::default();
+        while let Some(key) = refs.pop() {
+            if !visited.insert(key.clone()) {
+                continue;
+            }
+            if let Some(type_import) = self.type_imports.remove(&key) {
+                self.load_imported_type(key.clone(), type_import);
+            }
+            if let Some(aliased) = self.type_aliases.get(&key) {
+                refs.extend(TypeRefCollector::collect(aliased));
+            }
+            if let Some(interface) = self.interfaces.get(&key) {
+                refs.extend(TypeRefCollector::collect(interface));
             }
-            _ => None,
         }
     }
-}
 
-fn extract_prop_name(expr: Expr, computed: bool) -> PropName {
-    match expr {
-        Expr::Ident(ident) => PropName::Ident(ident),
-        Expr::Lit(Lit::Str(str)) => PropName::Str(str),
-        Expr::Lit(Lit::Num(num)) => PropName::Num(num),
-        Expr::Lit(Lit::BigInt(bigint)) => PropName::BigInt(bigint),
-        _ => {
-            if computed {
-                PropName::Computed(ComputedPropName {
-                    expr: Box::new(expr),
+    fn load_imported_type(&mut self, key: (JsWord, SyntaxContext), type_import: TypeImport) {
+        let Some(path) = self.load_type_module(&type_import.importer, &type_import.src.value)
+        else {
+            // value imports may come from modules which aren't TypeScript
+            if type_import.type_only {
+                Diagnostic::FailedToLoadTypes {
+                    src: &type_import.src.value,
+                }
+                .emit(type_import.src.span, &self.options);
+            }
+            return;
+        };
+        if let Some(ident) = self.find_exported_type(&path, &type_import.imported, &mut vec![]) {
+            self.type_aliases.insert(
+                key,
+                TsType::TsTypeRef(TsTypeRef {
                     span: DUMMY_SP,
-                })
-            } else {
-                HANDLER.with(|handler| handler.span_err(expr.span(), "Unsupported prop key."));
-                PropName::Ident(quote_ident!(""))
+                    type_name: TsEntityName::Ident(ident),
+                    type_params: None,
+                }),
+            );
+        }
+    }
+
+    /// Load the imported module and collect type declarations in it.
+    /// Returned path is the key of loaded module.
+    fn load_type_module(&mut self, importer: &Path, src: &str) -> Option<PathBuf> {
+        let type_resolver = self.type_resolver.as_ref()?;
+        let path = type_resolver.resolve(importer, src)?;
+        if self.type_modules.contains_key(&path) {
+            return Some(path);
+        }
+
+        let mut module = (*type_resolver.load(&path)?).clone();
+        // each module has its own top level scope
+        module.visit_mut_with(&mut resolver(self.unresolved_mark, Mark::new(), true));
+        let module = Rc::new(module);
+        // insert before collecting, in case of circular imports
+        self.type_modules.insert(path.clone(), module.clone());
+
+        module.body.iter().for_each(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => match decl
+            {
+                Decl::TsInterface(ts_interface_decl) => {
+                    self.collect_ts_interface(ts_interface_decl)
+                }
+                Decl::TsTypeAlias(ts_type_alias_decl) => {
+                    self.collect_ts_type_alias(ts_type_alias_decl)
+                }
+                _ => {}
+            },
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+                self.register_imported_types(&path, import_decl);
+            }
+            _ => {}
+        });
+        Some(path)
+    }
+
+    fn find_exported_type(
+        &mut self,
+        path: &Path,
+        name: &JsWord,
+        visited: &mut Vec<(PathBuf, JsWord)>,
+    ) -> Option<Ident> {
+        if visited
+            .iter()
+            .any(|(visited_path, visited_name)| visited_path == path && visited_name == name)
+        {
+            return None;
+        }
+        visited.push((path.to_path_buf(), name.clone()));
+
+        let module = self.type_modules.get(path)?.clone();
+        module.body.iter().find_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => match decl {
+                Decl::TsInterface(ts_interface_decl) if ts_interface_decl.id.sym == *name => {
+                    Some(ts_interface_decl.id.clone())
+                }
+                Decl::TsTypeAlias(ts_type_alias_decl) if ts_type_alias_decl.id.sym == *name => {
+                    Some(ts_type_alias_decl.id.clone())
+                }
+                _ => None,
+            },
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                specifiers,
+                src,
+                ..
+            })) => specifiers.iter().find_map(|specifier| {
+                let ExportSpecifier::Named(ExportNamedSpecifier { orig, exported, .. }) = specifier
+                else {
+                    return None;
+                };
+                if module_export_name_sym(exported.as_ref().unwrap_or(orig)) != name {
+                    return None;
+                }
+                match (src, orig) {
+                    (Some(src), _) => {
+                        let src_path = self.load_type_module(path, &src.value)?;
+                        self.find_exported_type(&src_path, module_export_name_sym(orig), visited)
+                    }
+                    (None, ModuleExportName::Ident(ident)) => Some(ident.clone()),
+                    (None, ModuleExportName::Str(..)) => None,
+                }
+            }),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll { src, .. })) => {
+                let src_path = self.load_type_module(path, &src.value)?;
+                self.find_exported_type(&src_path, name, visited)
+            }
+            _ => None,
+        })
+    }
+
+    fn extract_prop_name(&self, expr: Expr, computed: bool) -> PropName {
+        match expr {
+            Expr::Ident(ident) => PropName::Ident(ident),
+            Expr::Lit(Lit::Str(str)) => PropName::Str(str),
+            Expr::Lit(Lit::Num(num)) => PropName::Num(num),
+            Expr::Lit(Lit::BigInt(bigint)) => PropName::BigInt(bigint),
+            _ => {
+                if computed {
+                    PropName::Computed(ComputedPropName {
+                        expr: Box::new(expr),
+                        span: DUMMY_SP,
+                    })
+                } else {
+                    Diagnostic::UnsupportedPropKey.emit(expr.span(), &self.options);
+                    PropName::Ident(quote_ident!(""))
+                }
             }
         }
     }
 }
 
-fn try_unwrap_lit_prop_name(prop_name: &PropName) -> Option<Cow<PropName>> {
+fn try_unwrap_lit_prop_name(prop_name: &PropName) -> Option<Cow<'_, PropName>> {
     match prop_name {
         PropName::Ident(..) | PropName::Str(..) | PropName::Num(..) | PropName::BigInt(..) => {
             Some(Cow::Borrowed(prop_name))
@@ -1213,6 +1736,214 @@ fn try_unwrap_lit_prop_name(prop_name: &PropName) -> Option<Cow<PropName>> {
     }
 }
 
+/// Find `Emits` from the type of setup context like `(props, ctx: SetupContext<Emits>) => {}`.
+fn extract_setup_context_emits(setup_fn: &ExprOrSpread) -> Option<&TsType> {
+    let TsTypeAnn {
+        type_ann: second_param_type,
+        ..
+    } = (if let ExprOrSpread { expr, spread: None } = setup_fn {
+        match &**expr {
+            Expr::Arrow(arrow) => match arrow.params.get(1) {
+                Some(Pat::Ident(ident)) => ident.type_ann.as_deref(),
+                Some(Pat::Array(array)) => array.type_ann.as_deref(),
+                Some(Pat::Object(object)) => object.type_ann.as_deref(),
+                _ => return None,
+            },
+            Expr::Fn(fn_expr) => match fn_expr.function.params.get(1).map(|param| &param.pat) {
+                Some(Pat::Ident(ident)) => ident.type_ann.as_deref(),
+                Some(Pat::Array(array)) => array.type_ann.as_deref(),
+                Some(Pat::Object(object)) => object.type_ann.as_deref(),
+                _ => return None,
+            },
+            _ => return None,
+        }
+    } else {
+        return None;
+    })?;
+
+    match &**second_param_type {
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(ident),
+            type_params: Some(type_params),
+            ..
+        }) if ident.sym == "SetupContext" => {
+            type_params.params.first().map(|emits_def| &**emits_def)
+        }
+        _ => None,
+    }
+}
+
+fn extract_emit_name(key: &Expr) -> Vec<JsWord> {
+    match key {
+        Expr::Ident(ident) => vec![ident.sym.clone()],
+        Expr::Lit(Lit::Str(str)) => vec![str.value.clone()],
+        _ => vec![],
+    }
+}
+
+/// Build check of runtime type like `typeof value === "string"` or `value instanceof Date`.
+fn build_runtime_type_check(ident: &Ident, ty: &str) -> Expr {
+    let value = Expr::Ident(ident.clone());
+    let type_of = |expected: &str| {
+        build_bin_expr(
+            op!("==="),
+            Expr::Unary(UnaryExpr {
+                op: op!("typeof"),
+                arg: Box::new(value.clone()),
+                span: DUMMY_SP,
+            }),
+            Expr::Lit(Lit::Str(quote_str!(expected))),
+        )
+    };
+    match ty {
+        "String" | "Number" | "Boolean" | "Symbol" | "BigInt" | "Function" => {
+            type_of(&ty.to_ascii_lowercase())
+        }
+        "Object" => build_bin_expr(
+            op!("&&"),
+            type_of("object"),
+            build_bin_expr(
+                op!("!=="),
+                value.clone(),
+                Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+            ),
+        ),
+        "Array" => Expr::Call(CallExpr {
+            callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                obj: Box::new(Expr::Ident(quote_ident!("Array"))),
+                prop: MemberProp::Ident(quote_ident!("isArray")),
+                span: DUMMY_SP,
+            }))),
+            args: vec![ExprOrSpread {
+                expr: Box::new(value),
+                spread: None,
+            }],
+            type_args: None,
+            span: DUMMY_SP,
+        }),
+        _ => build_bin_expr(op!("instanceof"), value, Expr::Ident(quote_ident!(ty))),
+    }
+}
+
+/// Merge overloads of the same event into one emit,
+/// whose payload is valid if it matches any of overloads.
+/// If any of overloads can't be validated, the event isn't validated either.
+fn merge_emit_overloads(emits: Vec<EmitIr>) -> Vec<EmitIr> {
+    let mut overloads = IndexMap::<JsWord, Vec<Option<Expr>>>::with_capacity(emits.len());
+    for EmitIr { name, validator } in emits {
+        overloads.entry(name).or_default().push(validator);
+    }
+    overloads
+        .into_iter()
+        .map(|(name, validators)| EmitIr {
+            name,
+            validator: validators
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .and_then(merge_emit_validators),
+        })
+        .collect()
+}
+
+/// Combine validators by `||`, while parameters are renamed to those of the first one,
+/// since parameters at the same position may have different names in each overload.
+fn merge_emit_validators(validators: Vec<Expr>) -> Option<Expr> {
+    if validators.len() == 1 {
+        return validators.into_iter().next();
+    }
+    let mut params = Vec::<Ident>::new();
+    let mut checks = Vec::with_capacity(validators.len());
+    for validator in validators {
+        let Expr::Arrow(ArrowExpr {
+            params: arrow_params,
+            body,
+            ..
+        }) = validator
+        else {
+            return None;
+        };
+        let BlockStmtOrExpr::Expr(mut check) = *body else {
+            return None;
+        };
+        let mut renamer = ParamRenamer::default();
+        for (index, param) in arrow_params.into_iter().enumerate() {
+            let Pat::Ident(BindingIdent { id, .. }) = param else {
+                return None;
+            };
+            match params.get(index) {
+                Some(base) if base.sym != id.sym => {
+                    renamer.0.insert(id.sym, base.clone());
+                }
+                Some(..) => {}
+                None => {
+                    let ident = if params.iter().any(|base| base.sym == id.sym) {
+                        quote_ident!(format!("arg{index}"))
+                    } else {
+                        id.clone()
+                    };
+                    if ident.sym != id.sym {
+                        renamer.0.insert(id.sym, ident.clone());
+                    }
+                    params.push(ident);
+                }
+            }
+        }
+        check.visit_mut_with(&mut renamer);
+        checks.push(*check);
+    }
+
+    let check = checks
+        .into_iter()
+        .reduce(|left, right| build_bin_expr(op!("||"), left, right))?;
+    Some(Expr::Arrow(ArrowExpr {
+        params: params
+            .into_iter()
+            .map(|ident| Pat::Ident(ident.into()))
+            .collect(),
+        body: Box::new(BlockStmtOrExpr::Expr(Box::new(check))),
+        is_async: false,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+        span: DUMMY_SP,
+    }))
+}
+
+/// Rename parameters referenced in the body of emit validator.
+#[derive(Default)]
+struct ParamRenamer(FnvHashMap<JsWord, Ident>);
+
+impl VisitMut for ParamRenamer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Ident(ident) => {
+                if let Some(renamed) = self.0.get(&ident.sym) {
+                    *ident = renamed.clone();
+                }
+            }
+            _ => expr.visit_mut_children_with(self),
+        }
+    }
+}
+
+fn build_bin_expr(op: BinaryOp, left: Expr, right: Expr) -> Expr {
+    Expr::Bin(BinExpr {
+        op,
+        left: Box::new(left),
+        right: Box::new(right),
+        span: DUMMY_SP,
+    })
+}
+
+fn module_export_name_sym(name: &ModuleExportName) -> &JsWord {
+    match name {
+        ModuleExportName::Ident(ident) => &ident.sym,
+        ModuleExportName::Str(str) => &str.value,
+    }
+}
+
 fn extract_type_ann_from_pat(pat: &Pat) -> Option<&TsTypeAnn> {
     match pat {
         Pat::Ident(ident) => ident.type_ann.as_deref(),
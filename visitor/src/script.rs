@@ -0,0 +1,217 @@
+use crate::{util, Options, VueJsxTransformVisitor};
+use std::mem;
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{quote_ident, quote_str},
+    },
+};
+
+impl<C> VueJsxTransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Collect helpers and `defineComponent` destructured from `require` of the runtime package
+    /// or the global object of runtime,
+    /// like `const { createVNode, defineComponent } = require("vue")`.
+    pub(crate) fn collect_user_vue_requires<'a>(&mut self, stmts: impl Iterator<Item = &'a Stmt>) {
+        let props = stmts
+            .filter_map(|stmt| match stmt {
+                Stmt::Decl(Decl::Var(var_decl)) => Some(&var_decl.decls),
+                _ => None,
+            })
+            .flatten()
+            .filter(|var_declarator| is_user_vue_require(var_declarator, &self.options))
+            .filter_map(|var_declarator| match &var_declarator.name {
+                Pat::Object(ObjectPat { props, .. }) => Some(props),
+                _ => None,
+            })
+            .flatten();
+        for prop in props {
+            let (imported, local) = match prop {
+                ObjectPatProp::Assign(AssignPatProp {
+                    key, value: None, ..
+                }) => (key.sym.clone(), key.id.clone()),
+                ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                    let imported = match key {
+                        PropName::Ident(ident) => ident.sym.clone(),
+                        PropName::Str(str) => str.value.clone(),
+                        _ => continue,
+                    };
+                    let Pat::Ident(BindingIdent { id, .. }) = &**value else {
+                        continue;
+                    };
+                    (imported, id.clone())
+                }
+                _ => continue,
+            };
+            if imported == "defineComponent" {
+                self.define_component = Some(local.span.ctxt());
+            }
+            self.user_vue_imports.entry(imported).or_insert(local);
+        }
+    }
+
+    /// Get the position after the last statement destructuring helpers from runtime,
+    /// where constants initialized by those helpers can be placed safely.
+    pub(crate) fn find_after_user_vue_requires<'a>(
+        &self,
+        mut stmts: impl DoubleEndedIterator<Item = Option<&'a Stmt>> + ExactSizeIterator,
+    ) -> Option<usize> {
+        stmts
+            .rposition(|stmt| {
+                matches!(stmt, Some(Stmt::Decl(Decl::Var(var_decl)))
+                    if var_decl.decls.iter().any(|var_declarator| is_user_vue_require(var_declarator, &self.options)))
+            })
+            .map(|index| index + 1)
+    }
+
+    /// Scripts can't contain import declarations,
+    /// so helpers are destructured from `require` at the top of the script.
+    pub(crate) fn inject_script_helpers(&mut self, script: &mut Script) {
+        let mut prelude = vec![];
+        // helper of slots must be built first, since it requires another runtime helper
+        let slot_helper = self.slot_helper_ident.clone().map(|slot_helper| {
+            Stmt::Decl(Decl::Fn(util::build_slot_helper(
+                slot_helper,
+                self.import_from_vue("isVNode"),
+            )))
+        });
+
+        let vue_imports = self.collect_vue_imports();
+        if !vue_imports.is_empty() {
+            let init = if let Some(runtime_global) = &self.options.runtime_global {
+                util::build_global_access(runtime_global)
+            } else {
+                build_require_call(&self.options.import_source)
+            };
+            prelude.push(util::build_destructuring(vue_imports.into_iter(), init));
+        }
+
+        if !self.server_renderer_imports.is_empty() {
+            let server_renderer_imports = self
+                .server_renderer_imports
+                .iter()
+                .map(|(imported, local)| (*imported, local.clone()));
+            prelude.push(util::build_destructuring(
+                server_renderer_imports,
+                build_require_call("@vue/server-renderer"),
+            ));
+        }
+
+        if let Some(helper) = &self.transform_on_helper {
+            prelude.push(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(helper.clone().into()),
+                    init: Some(Box::new(build_require_call(
+                        "@vue/babel-helper-vue-transform-on",
+                    ))),
+                    definite: false,
+                }],
+            }))));
+        }
+
+        prelude.extend(slot_helper);
+
+        if !self.injecting_vars.is_empty() {
+            prelude.push(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Let,
+                declare: false,
+                decls: mem::take(&mut self.injecting_vars),
+            }))));
+            self.slot_counter = 1;
+        }
+
+        if !self.injecting_consts.is_empty() {
+            prelude.push(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: mem::take(&mut self.injecting_consts),
+            }))));
+        }
+
+        // unlike imports, `require` isn't hoisted, so it must be called before hoisted vnodes
+        let mut hoisted = vec![];
+        hoisted.extend(self.take_module_assets());
+        if !self.hoisted_consts.is_empty() {
+            hoisted.push(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: mem::take(&mut self.hoisted_consts),
+            }))));
+        }
+
+        // helpers destructured by user can't be accessed before their declarations
+        match self.find_after_user_vue_requires(script.body.iter().map(Some)) {
+            Some(index) => {
+                script.body.splice(index..index, hoisted);
+            }
+            None => prelude.extend(hoisted),
+        }
+
+        // directives like "use strict" must be kept at the top
+        let index = script
+            .body
+            .iter()
+            .position(|stmt| !is_directive(stmt))
+            .unwrap_or(script.body.len());
+        script.body.splice(index..index, prelude);
+    }
+}
+
+fn is_user_vue_require(var_declarator: &VarDeclarator, options: &Options) -> bool {
+    match var_declarator {
+        VarDeclarator {
+            name: Pat::Object(..),
+            init: Some(init),
+            ..
+        } => {
+            is_require_of(init, &options.import_source)
+                || options
+                    .runtime_global
+                    .as_deref()
+                    .is_some_and(|runtime_global| util::is_global_access(init, runtime_global))
+        }
+        _ => false,
+    }
+}
+
+fn is_directive(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Expr(ExprStmt { expr, .. }) if matches!(&**expr, Expr::Lit(Lit::Str(..))))
+}
+
+fn is_require_of(expr: &Expr, src: &str) -> bool {
+    matches!(
+        expr,
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) if matches!(&**callee, Expr::Ident(ident) if ident.sym == "require")
+            && matches!(
+                args.as_slice(),
+                [ExprOrSpread { spread: None, expr }]
+                    if matches!(&**expr, Expr::Lit(Lit::Str(str)) if str.value == *src)
+            )
+    )
+}
+
+fn build_require_call(src: &str) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Box::new(Expr::Ident(quote_ident!("require")))),
+        args: vec![ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Lit(Lit::Str(quote_str!(src)))),
+        }],
+        type_args: None,
+    })
+}
@@ -0,0 +1,631 @@
+use crate::{
+    directive::is_directive,
+    inject_define_component_option, util,
+    v_if::{self, ChildOrVIfChain},
+    BlockType, VueJsxTransformVisitor, FRAGMENT, TELEPORT,
+};
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{
+        ast::*,
+        atoms::Atom,
+        utils::{private_ident, quote_ident, quote_str},
+        visit::{noop_visit_type, Visit, VisitMutWith, VisitWith},
+    },
+};
+
+const VOID_TAGS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Built-in types which aren't components, so they can't be rendered by `ssrRenderComponent`.
+const NON_COMPONENT_TYPES: [&str; 3] = [FRAGMENT, TELEPORT, "Suspense"];
+
+/// Binding of slots in the setup context,
+/// which is either destructured like `{ slots }` or accessed like `ctx.slots`.
+enum SlotsBinding {
+    Slots(Id),
+    Context(Id),
+}
+
+/// Code of the inline SSR render function.
+/// Consecutive strings are concatenated and pushed at once.
+struct SsrOutput {
+    push: Ident,
+    parent: Ident,
+    slots: Option<SlotsBinding>,
+    quasis: Vec<String>,
+    exprs: Vec<Expr>,
+    stmts: Vec<Stmt>,
+}
+
+impl SsrOutput {
+    fn push_str(&mut self, str: &str) {
+        if str.is_empty() {
+            return;
+        }
+        match self.quasis.last_mut() {
+            Some(quasi) => quasi.push_str(str),
+            None => self.quasis.push(str.to_string()),
+        }
+    }
+
+    fn push_expr(&mut self, expr: Expr) {
+        if self.quasis.is_empty() {
+            self.quasis.push(String::new());
+        }
+        self.exprs.push(expr);
+        self.quasis.push(String::new());
+    }
+
+    fn push_stmt(&mut self, expr: Expr) {
+        self.flush();
+        self.stmts.push(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(expr),
+        }));
+    }
+
+    fn flush(&mut self) {
+        if self.quasis.is_empty() {
+            return;
+        }
+        let quasis = std::mem::take(&mut self.quasis);
+        let len = quasis.len();
+        let tpl = Expr::Tpl(Tpl {
+            span: DUMMY_SP,
+            exprs: std::mem::take(&mut self.exprs)
+                .into_iter()
+                .map(Box::new)
+                .collect(),
+            quasis: quasis
+                .into_iter()
+                .enumerate()
+                .map(|(i, quasi)| TplElement {
+                    span: DUMMY_SP,
+                    tail: i == len - 1,
+                    raw: Atom::from(escape_tpl_raw(&quasi)),
+                    cooked: Some(Atom::from(quasi)),
+                })
+                .collect(),
+        });
+        self.stmts.push(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(self.push.clone()))),
+                args: vec![ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(tpl),
+                }],
+                type_args: None,
+            })),
+        }));
+    }
+}
+
+impl<C> VueJsxTransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Compile render functions returned from `setup` of the component
+    /// to inline SSR render functions.
+    pub(crate) fn transform_ssr_component(&mut self, call_expr: &mut CallExpr) {
+        let slots = util::find_setup_context(call_expr).and_then(find_slots_binding);
+        let Some(render) = util::find_setup_render(call_expr) else {
+            return;
+        };
+        if !self.transform_ssr_render(render, slots) {
+            return;
+        }
+        let flag = Expr::Lit(Lit::Bool(true.into()));
+        match call_expr.args.first_mut().map(|arg| &mut *arg.expr) {
+            Some(Expr::Object(ObjectLit { props, .. })) => {
+                props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!("__ssrInlineRender")),
+                    value: Box::new(flag),
+                }))));
+            }
+            _ => inject_define_component_option(call_expr, "__ssrInlineRender", flag),
+        }
+    }
+
+    /// Replace body of the render function with SSR code
+    /// if it returns JSX which can be rendered to strings.
+    fn transform_ssr_render(
+        &mut self,
+        render: &mut ArrowExpr,
+        slots: Option<SlotsBinding>,
+    ) -> bool {
+        if !render.params.is_empty() || render.is_async || render.is_generator {
+            return false;
+        }
+        let (prelude, root) = match &mut *render.body {
+            BlockStmtOrExpr::Expr(expr) => (vec![], &mut **expr),
+            BlockStmtOrExpr::BlockStmt(BlockStmt { stmts, .. }) => {
+                // other return statements can't be handled
+                let Some((Stmt::Return(ReturnStmt { arg: Some(arg), .. }), prelude)) =
+                    stmts.split_last_mut()
+                else {
+                    return false;
+                };
+                if prelude.iter().any(contains_return) {
+                    return false;
+                }
+                (prelude.to_vec(), &mut **arg)
+            }
+        };
+        let root = match root {
+            Expr::Paren(ParenExpr { expr, .. }) => &mut **expr,
+            root => root,
+        };
+        let is_root_renderable = match root {
+            Expr::JSXElement(jsx_element) => {
+                self.is_component(&jsx_element.opening.name)
+                    || self.is_ssr_renderable_element(jsx_element)
+            }
+            Expr::JSXFragment(..) => true,
+            _ => false,
+        };
+        if !is_root_renderable {
+            return false;
+        }
+
+        let ctx = private_ident!("_ctx");
+        let attrs = private_ident!("_attrs");
+        let mut output = SsrOutput {
+            push: private_ident!("_push"),
+            parent: private_ident!("_parent"),
+            slots,
+            quasis: vec![],
+            exprs: vec![],
+            stmts: prelude,
+        };
+        // the render function is compiled before it's visited,
+        // so its assets must be resolved in its own scope rather than the outer one
+        self.enter_asset_scope();
+        // JSX nested in expressions is transformed as usual
+        root.visit_mut_children_with(self);
+        match root {
+            Expr::JSXElement(jsx_element) => {
+                self.render_ssr_element(jsx_element, Some(&attrs), &mut output)
+            }
+            Expr::JSXFragment(jsx_fragment) => {
+                self.render_ssr_fragment(&jsx_fragment.children, &mut output)
+            }
+            _ => unreachable!(),
+        }
+        output.flush();
+        if let Some(assets) = self.leave_asset_scope() {
+            output.stmts.insert(0, assets);
+        }
+
+        render.params = [ctx, output.push, output.parent, attrs]
+            .into_iter()
+            .map(|ident| Pat::Ident(ident.into()))
+            .collect();
+        *render.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+            span: DUMMY_SP,
+            stmts: output.stmts,
+        });
+        true
+    }
+
+    /// Check if the native element can be rendered to strings by attributes helper,
+    /// while directives and props which change its content are left to vnodes.
+    fn is_ssr_renderable_element(&self, jsx_element: &JSXElement) -> bool {
+        let JSXElementName::Ident(tag) = &jsx_element.opening.name else {
+            return false;
+        };
+        self.is_native_tag(&tag.sym)
+            && jsx_element
+                .opening
+                .attrs
+                .iter()
+                .all(|jsx_attr_or_spread| match jsx_attr_or_spread {
+                    JSXAttrOrSpread::JSXAttr(jsx_attr) => {
+                        !is_directive(jsx_attr)
+                            && match &jsx_attr.name {
+                                JSXAttrName::Ident(ident) => {
+                                    ident.sym != "innerHTML"
+                                        && ident.sym != "textContent"
+                                        && !(tag.sym == "textarea" && ident.sym == "value")
+                                }
+                                JSXAttrName::JSXNamespacedName(..) => true,
+                            }
+                    }
+                    JSXAttrOrSpread::SpreadElement(..) => false,
+                })
+            && jsx_element
+                .children
+                .iter()
+                .all(|child| !matches!(child, JSXElementChild::JSXSpreadChild(..)))
+    }
+
+    /// Render the element and its descendants.
+    /// Attributes from parent component are merged if it's the root.
+    fn render_ssr_element(
+        &mut self,
+        jsx_element: &JSXElement,
+        fallthrough_attrs: Option<&Ident>,
+        output: &mut SsrOutput,
+    ) {
+        if self.is_component(&jsx_element.opening.name) {
+            self.render_ssr_component(jsx_element, fallthrough_attrs, output);
+            return;
+        }
+        if !self.is_ssr_renderable_element(jsx_element) {
+            let vnode = self.transform_jsx_element(jsx_element, BlockType::None);
+            let render_vnode = self.build_ssr_render_vnode(vnode, output);
+            output.push_stmt(render_vnode);
+            return;
+        }
+        let JSXElementName::Ident(tag) = &jsx_element.opening.name else {
+            unreachable!("renderable element must have plain tag name")
+        };
+
+        output.push_str(&format!("<{}", tag.sym));
+        let attrs = self
+            .transform_attrs(&jsx_element.opening, false, &mut vec![])
+            .attrs;
+        let attrs = match (attrs, fallthrough_attrs) {
+            (Expr::Lit(Lit::Null(..)), None) => None,
+            (Expr::Lit(Lit::Null(..)), Some(fallthrough_attrs)) => {
+                Some(Expr::Ident(fallthrough_attrs.clone()))
+            }
+            (attrs, None) => Some(attrs),
+            (attrs, Some(fallthrough_attrs)) => Some(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(self.import_from_vue("mergeProps")))),
+                args: vec![attrs.into(), Expr::Ident(fallthrough_attrs.clone()).into()],
+                type_args: None,
+            })),
+        };
+        if let Some(attrs) = attrs {
+            let render_attrs = Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(
+                    self.import_from_server_renderer("ssrRenderAttrs"),
+                ))),
+                args: vec![
+                    attrs.into(),
+                    Expr::Lit(Lit::Str(quote_str!(tag.sym.clone()))).into(),
+                ],
+                type_args: None,
+            });
+            output.push_expr(render_attrs);
+        }
+        output.push_str(">");
+
+        if VOID_TAGS.contains(&&*tag.sym) {
+            return;
+        }
+        self.render_ssr_children(&jsx_element.children, output);
+        output.push_str(&format!("</{}>", tag.sym));
+    }
+
+    fn render_ssr_component(
+        &mut self,
+        jsx_element: &JSXElement,
+        fallthrough_attrs: Option<&Ident>,
+        output: &mut SsrOutput,
+    ) {
+        let is_component_type = match &jsx_element.opening.name {
+            JSXElementName::Ident(Ident { sym, .. })
+            | JSXElementName::JSXMemberExpr(JSXMemberExpr {
+                prop: Ident { sym, .. },
+                ..
+            }) => !NON_COMPONENT_TYPES
+                .iter()
+                .any(|non_component| util::is_built_in_type(sym, non_component)),
+            JSXElementName::JSXNamespacedName(..) => true,
+        };
+        let vnode = self.transform_jsx_element(jsx_element, BlockType::None);
+        let pragma = self.get_pragma();
+        let args = match &vnode {
+            // components with directives are wrapped by `withDirectives`
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) if is_component_type
+                && matches!(&**callee, Expr::Ident(ident) if ident.to_id() == pragma.to_id()) =>
+            {
+                args
+            }
+            _ => {
+                let render_vnode = self.build_ssr_render_vnode(vnode, output);
+                output.push_stmt(render_vnode);
+                return;
+            }
+        };
+
+        let mut args = args.iter().take(3).cloned().collect::<Vec<_>>();
+        if let Some(fallthrough_attrs) = fallthrough_attrs {
+            let attrs = match *args[1].expr.clone() {
+                Expr::Lit(Lit::Null(..)) => Expr::Ident(fallthrough_attrs.clone()),
+                attrs => Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: Callee::Expr(Box::new(Expr::Ident(self.import_from_vue("mergeProps")))),
+                    args: vec![attrs.into(), Expr::Ident(fallthrough_attrs.clone()).into()],
+                    type_args: None,
+                }),
+            };
+            args[1] = attrs.into();
+        }
+        args.push(Expr::Ident(output.parent.clone()).into());
+        let render_component = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Ident(
+                self.import_from_server_renderer("ssrRenderComponent"),
+            ))),
+            args,
+            type_args: None,
+        });
+        let push = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Ident(output.push.clone()))),
+            args: vec![render_component.into()],
+            type_args: None,
+        });
+        output.push_stmt(push);
+    }
+
+    fn render_ssr_fragment(&mut self, children: &[JSXElementChild], output: &mut SsrOutput) {
+        output.push_str("<!--[-->");
+        self.render_ssr_children(children, output);
+        output.push_str("<!--]-->");
+    }
+
+    fn render_ssr_children(&mut self, children: &[JSXElementChild], output: &mut SsrOutput) {
+        let mut v_if_key = 0;
+        v_if::group_v_if_chains(children)
+            .into_iter()
+            .for_each(|child| match child {
+                ChildOrVIfChain::VIfChain(chain) => {
+                    let vnode = self.transform_v_if_chain(&chain, &mut v_if_key);
+                    let render_vnode = self.build_ssr_render_vnode(vnode, output);
+                    output.push_stmt(render_vnode);
+                }
+                ChildOrVIfChain::Child(child) => self.render_ssr_child(child, output),
+            });
+    }
+
+    fn render_ssr_child(&mut self, child: &JSXElementChild, output: &mut SsrOutput) {
+        match child {
+            JSXElementChild::JSXText(jsx_text) => {
+                output.push_str(&escape_html(&util::transform_text(&jsx_text.value)));
+            }
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::JSXEmptyExpr(..),
+                ..
+            }) => {}
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => self.render_ssr_expr(expr, output),
+            JSXElementChild::JSXElement(jsx_element) => {
+                self.render_ssr_element(jsx_element, None, output)
+            }
+            JSXElementChild::JSXFragment(jsx_fragment) => {
+                self.render_ssr_fragment(&jsx_fragment.children, output)
+            }
+            JSXElementChild::JSXSpreadChild(..) => {
+                unreachable!("element with spread children must be rendered as vnode")
+            }
+        }
+    }
+
+    fn render_ssr_expr(&mut self, expr: &Expr, output: &mut SsrOutput) {
+        if is_text_expr(expr) {
+            let interpolate = Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(
+                    self.import_from_server_renderer("ssrInterpolate"),
+                ))),
+                args: vec![expr.clone().into()],
+                type_args: None,
+            });
+            output.push_expr(interpolate);
+        } else if let Some((slots, name, slot_props)) = as_slot_call(expr, output.slots.as_ref()) {
+            let render_slot = Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(
+                    self.import_from_server_renderer("ssrRenderSlot"),
+                ))),
+                args: vec![
+                    slots.into(),
+                    Expr::Lit(Lit::Str(quote_str!(name))).into(),
+                    slot_props
+                        .unwrap_or_else(|| {
+                            Expr::Object(ObjectLit {
+                                span: DUMMY_SP,
+                                props: vec![],
+                            })
+                        })
+                        .into(),
+                    Expr::Lit(Lit::Null(Null { span: DUMMY_SP })).into(),
+                    Expr::Ident(output.push.clone()).into(),
+                    Expr::Ident(output.parent.clone()).into(),
+                ],
+                type_args: None,
+            });
+            output.push_stmt(render_slot);
+        } else {
+            // children can be anything which can be normalized to vnodes
+            let vnode = Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                    span: DUMMY_SP,
+                    obj: Box::new(Expr::Ident(self.import_from_vue("ssrUtils"))),
+                    prop: MemberProp::Ident(quote_ident!("normalizeVNode")),
+                }))),
+                args: vec![expr.clone().into()],
+                type_args: None,
+            });
+            let render_vnode = self.build_ssr_render_vnode(vnode, output);
+            output.push_stmt(render_vnode);
+        }
+    }
+
+    fn build_ssr_render_vnode(&mut self, vnode: Expr, output: &SsrOutput) -> Expr {
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Ident(
+                self.import_from_server_renderer("ssrRenderVNode"),
+            ))),
+            args: vec![
+                Expr::Ident(output.push.clone()).into(),
+                vnode.into(),
+                Expr::Ident(output.parent.clone()).into(),
+            ],
+            type_args: None,
+        })
+    }
+}
+
+/// Check if the expression is always evaluated to string or number,
+/// which is rendered as text node.
+fn is_text_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(Lit::Str(..) | Lit::Num(..) | Lit::BigInt(..)) | Expr::Tpl(..) => true,
+        Expr::Bin(BinExpr { op, .. }) => matches!(
+            op,
+            BinaryOp::Add
+                | BinaryOp::Sub
+                | BinaryOp::Mul
+                | BinaryOp::Div
+                | BinaryOp::Mod
+                | BinaryOp::Exp
+                | BinaryOp::BitOr
+                | BinaryOp::BitAnd
+                | BinaryOp::BitXor
+                | BinaryOp::LShift
+                | BinaryOp::RShift
+                | BinaryOp::ZeroFillRShift
+        ),
+        Expr::Unary(UnaryExpr { op, .. }) => matches!(
+            op,
+            UnaryOp::Minus | UnaryOp::Plus | UnaryOp::Tilde | UnaryOp::TypeOf
+        ),
+        Expr::Cond(CondExpr { cons, alt, .. }) => is_text_expr(cons) && is_text_expr(alt),
+        Expr::Paren(ParenExpr { expr, .. }) => is_text_expr(expr),
+        _ => false,
+    }
+}
+
+/// Find the binding of slots from parameter of the setup context.
+fn find_slots_binding(context: &Pat) -> Option<SlotsBinding> {
+    match context {
+        Pat::Ident(ident) => Some(SlotsBinding::Context(ident.to_id())),
+        Pat::Object(ObjectPat { props, .. }) => props.iter().find_map(|prop| match prop {
+            ObjectPatProp::Assign(AssignPatProp { key, .. }) if key.sym == "slots" => {
+                Some(SlotsBinding::Slots(key.to_id()))
+            }
+            ObjectPatProp::KeyValue(KeyValuePatProp {
+                key: PropName::Ident(Ident { sym, .. }) | PropName::Str(Str { value: sym, .. }),
+                value,
+            }) if sym == "slots" => match &**value {
+                Pat::Ident(ident) => Some(SlotsBinding::Slots(ident.to_id())),
+                _ => None,
+            },
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Match calls like `slots.default(props)` or `ctx.slots.default(props)`,
+/// where slots are bound from the setup context.
+/// Optional calls are excluded, since missing slots are rendered as comments by vnodes.
+fn as_slot_call<'a>(
+    expr: &'a Expr,
+    slots: Option<&SlotsBinding>,
+) -> Option<(Expr, &'a str, Option<Expr>)> {
+    let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+    }) = expr
+    else {
+        return None;
+    };
+    let Expr::Member(MemberExpr { obj, prop, .. }) = &**callee else {
+        return None;
+    };
+    let is_slots = match (slots, &**obj) {
+        (Some(SlotsBinding::Slots(id)), Expr::Ident(ident)) => ident.to_id() == *id,
+        (
+            Some(SlotsBinding::Context(id)),
+            Expr::Member(MemberExpr {
+                obj: context,
+                prop: MemberProp::Ident(prop),
+                ..
+            }),
+        ) => {
+            prop.sym == "slots" && matches!(&**context, Expr::Ident(ident) if ident.to_id() == *id)
+        }
+        _ => false,
+    };
+    let name = match prop {
+        MemberProp::Ident(ident) => &*ident.sym,
+        MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(str)) => &*str.value,
+            _ => return None,
+        },
+        MemberProp::PrivateName(..) => return None,
+    };
+    match args.as_slice() {
+        _ if !is_slots => None,
+        [] => Some(((**obj).clone(), name, None)),
+        [ExprOrSpread { spread: None, expr }] => {
+            Some(((**obj).clone(), name, Some((**expr).clone())))
+        }
+        _ => None,
+    }
+}
+
+fn contains_return(stmt: &Stmt) -> bool {
+    let mut finder = ReturnFinder { found: false };
+    stmt.visit_with(&mut finder);
+    finder.found
+}
+
+struct ReturnFinder {
+    found: bool,
+}
+
+impl Visit for ReturnFinder {
+    noop_visit_type!();
+
+    fn visit_return_stmt(&mut self, _: &ReturnStmt) {
+        self.found = true;
+    }
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+/// Escape text as same as `escapeHtml` of `@vue/shared`.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    text.chars().for_each(|c| match c {
+        '"' => escaped.push_str("&quot;"),
+        '&' => escaped.push_str("&amp;"),
+        '\'' => escaped.push_str("&#39;"),
+        '<' => escaped.push_str("&lt;"),
+        '>' => escaped.push_str("&gt;"),
+        c => escaped.push(c),
+    });
+    escaped
+}
+
+fn escape_tpl_raw(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
@@ -0,0 +1,235 @@
+use fnv::FnvHashMap;
+use serde::Deserialize;
+use std::{
+    fs, io,
+    path::{Component, Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock},
+    time::SystemTime,
+};
+use swc_core::{
+    common::{sync::Lrc, FileName, SourceMap, Span, DUMMY_SP},
+    ecma::{
+        ast::{EsVersion, Module},
+        parser::{parse_file_as_module, Syntax, TsConfig},
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+/// Resolver for locating and loading modules that types are imported from,
+/// which is used when `resolveType` is enabled.
+pub trait TypeResolver {
+    /// Resolve the import source to a file path.
+    /// `importer` is the path of the file which contains the import declaration.
+    fn resolve(&self, importer: &Path, src: &str) -> Option<PathBuf>;
+
+    /// Load and parse the TypeScript module at the resolved path.
+    fn load(&self, path: &Path) -> Option<Arc<Module>>;
+}
+
+/// Default type resolver which reads `.ts` and `.d.ts` files from disk.
+///
+/// Besides relative paths, import sources can be mapped by `paths` of `tsconfig.json`.
+/// Parsed modules are cached globally, so they're shared across files.
+#[derive(Default)]
+pub struct FsTypeResolver {
+    base_url: PathBuf,
+    paths: Vec<(String, Vec<String>)>,
+}
+
+impl FsTypeResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create resolver with `baseUrl` and `paths` in `compilerOptions` of `tsconfig.json`.
+    pub fn from_tsconfig(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let json = strip_json_comments(&fs::read_to_string(path)?);
+        let TsConfigJson { compiler_options } = serde_json::from_str(&json)?;
+
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let base_url = match compiler_options.base_url {
+            Some(base_url) => dir.join(base_url),
+            None => dir.to_path_buf(),
+        };
+        let mut paths = compiler_options.paths.into_iter().collect::<Vec<_>>();
+        // longest prefix wins as TypeScript does
+        paths.sort_by_key(|(pattern, _)| {
+            std::cmp::Reverse(pattern.find('*').unwrap_or(pattern.len()))
+        });
+        Ok(Self { base_url, paths })
+    }
+
+    fn resolve_mapped_path(&self, src: &str) -> Option<PathBuf> {
+        self.paths.iter().find_map(|(pattern, targets)| {
+            let matched = match pattern.split_once('*') {
+                Some((prefix, suffix)) => src
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_suffix(suffix))?,
+                None if pattern == src => "",
+                None => return None,
+            };
+            targets.iter().find_map(|target| {
+                resolve_file(&self.base_url.join(target.replacen('*', matched, 1)))
+            })
+        })
+    }
+}
+
+impl TypeResolver for FsTypeResolver {
+    fn resolve(&self, importer: &Path, src: &str) -> Option<PathBuf> {
+        if src.starts_with("./") || src.starts_with("../") {
+            resolve_file(&importer.parent()?.join(src))
+        } else {
+            self.resolve_mapped_path(src)
+        }
+    }
+
+    fn load(&self, path: &Path) -> Option<Arc<Module>> {
+        static CACHE: OnceLock<Mutex<FnvHashMap<PathBuf, CachedModule>>> = OnceLock::new();
+
+        let modified = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        let mut cache = CACHE.get_or_init(Default::default).lock().ok()?;
+        if let Some(cached) = cache.get(path) {
+            if cached.modified == modified {
+                return Some(cached.module.clone());
+            }
+        }
+
+        let module = Arc::new(parse_module(path)?);
+        cache.insert(
+            path.to_path_buf(),
+            CachedModule {
+                modified,
+                module: module.clone(),
+            },
+        );
+        Some(module)
+    }
+}
+
+struct CachedModule {
+    modified: Option<SystemTime>,
+    module: Arc<Module>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfigJson {
+    #[serde(default)]
+    compiler_options: CompilerOptions,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct CompilerOptions {
+    base_url: Option<String>,
+    paths: FnvHashMap<String, Vec<String>>,
+}
+
+fn resolve_file(path: &Path) -> Option<PathBuf> {
+    let path = normalize_path(path);
+    let file_name = path.file_name()?.to_str()?;
+    let candidates = if file_name.ends_with(".ts") {
+        vec![path.clone()]
+    } else if let Some(stem) = file_name.strip_suffix(".js") {
+        vec![
+            path.with_file_name(format!("{stem}.ts")),
+            path.with_file_name(format!("{stem}.d.ts")),
+        ]
+    } else {
+        vec![
+            path.with_file_name(format!("{file_name}.ts")),
+            path.with_file_name(format!("{file_name}.d.ts")),
+            path.join("index.ts"),
+            path.join("index.d.ts"),
+        ]
+    };
+    candidates.into_iter().find(|candidate| candidate.is_file())
+}
+
+/// Remove `.` and `..` in path, so the same file is always cached with the same key.
+fn normalize_path(path: &Path) -> PathBuf {
+    path.components()
+        .fold(PathBuf::new(), |mut normalized, component| {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir if normalized.file_name().is_some() => {
+                    normalized.pop();
+                }
+                _ => normalized.push(component),
+            }
+            normalized
+        })
+}
+
+fn parse_module(path: &Path) -> Option<Module> {
+    let source = fs::read_to_string(path).ok()?;
+    let source_map = Lrc::new(SourceMap::default());
+    let source_file = source_map.new_source_file(FileName::Real(path.to_path_buf()), source);
+    let mut module = parse_file_as_module(
+        &source_file,
+        Syntax::Typescript(TsConfig {
+            dts: path.to_string_lossy().ends_with(".d.ts"),
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut vec![],
+    )
+    .ok()?;
+    // positions are only meaningful in the source map of this module
+    module.visit_mut_with(&mut SpanRemover);
+    Some(module)
+}
+
+struct SpanRemover;
+
+impl VisitMut for SpanRemover {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        *span = DUMMY_SP;
+    }
+}
+
+/// Remove comments and trailing commas, which are allowed in `tsconfig.json`.
+fn strip_json_comments(json: &str) -> String {
+    let mut output = String::with_capacity(json.len());
+    let mut chars = json.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                output.push(c);
+                while let Some(c) = chars.next() {
+                    output.push(c);
+                    match c {
+                        '\\' => output.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                chars.by_ref().find(|c| *c == '\n');
+                output.push('\n');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                chars
+                    .by_ref()
+                    .find(|c| std::mem::replace(&mut prev, *c) == '*' && *c == '/');
+            }
+            ']' | '}' => {
+                let trimmed_len = output.trim_end().len();
+                if output[..trimmed_len].ends_with(',') {
+                    output.truncate(trimmed_len - 1);
+                }
+                output.push(c);
+            }
+            _ => output.push(c),
+        }
+    }
+    output
+}
@@ -3,6 +3,7 @@ use swc_core::{
     ecma::{
         ast::*,
         utils::{private_ident, quote_ident, quote_str},
+        visit::{noop_visit_type, Visit, VisitWith},
     },
 };
 
@@ -127,6 +128,45 @@ fn is_constant(expr: &Expr) -> bool {
     }
 }
 
+/// Check if evaluating the expression may create vnodes immediately,
+/// either by JSX or by calling functions.
+/// Functions defined in the expression are skipped since they're evaluated lazily.
+pub(crate) fn may_create_vnodes(expr: &Expr) -> bool {
+    let mut finder = VNodeCreationFinder { found: false };
+    expr.visit_with(&mut finder);
+    finder.found
+}
+
+struct VNodeCreationFinder {
+    found: bool,
+}
+
+impl Visit for VNodeCreationFinder {
+    noop_visit_type!();
+
+    fn visit_jsx_element(&mut self, _: &JSXElement) {
+        self.found = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _: &JSXFragment) {
+        self.found = true;
+    }
+
+    fn visit_call_expr(&mut self, _: &CallExpr) {
+        self.found = true;
+    }
+
+    fn visit_opt_call(&mut self, _: &OptCall) {
+        self.found = true;
+    }
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
 pub(crate) fn is_on(attr_name: &str) -> bool {
     match attr_name.as_bytes() {
         [b'o', b'n', c, ..] => !c.is_ascii_lowercase(),
@@ -174,7 +214,7 @@ pub(crate) fn dedupe_props(props: Vec<PropOrSpread>) -> Vec<PropOrSpread> {
                                     expr: value,
                                 }));
                             } else {
-                                *defined_value = Box::new(Expr::Array(ArrayLit {
+                                **defined_value = Expr::Array(ArrayLit {
                                     span: DUMMY_SP,
                                     elems: vec![
                                         Some(ExprOrSpread {
@@ -186,7 +226,7 @@ pub(crate) fn dedupe_props(props: Vec<PropOrSpread>) -> Vec<PropOrSpread> {
                                             expr: value,
                                         }),
                                     ],
-                                }));
+                                });
                             }
                         }
                         Some(..) => {}
@@ -223,7 +263,7 @@ pub(crate) fn decouple_v_models(
             Some(ExprOrSpread { spread: None, expr }) => expr.array(),
             _ => None,
         })
-        .map(|ArrayLit { mut elems, .. }| {
+        .map(|ArrayLit { span, mut elems }| {
             let argument = elems
                 .get(1)
                 .and_then(|elem| {
@@ -244,7 +284,7 @@ pub(crate) fn decouple_v_models(
                 elems.remove(1);
             }
             JSXAttrOrSpread::JSXAttr(JSXAttr {
-                span: DUMMY_SP,
+                span,
                 name: if let Some(argument) = argument {
                     JSXAttrName::JSXNamespacedName(JSXNamespacedName {
                         ns: quote_ident!("v-model"),
@@ -254,11 +294,8 @@ pub(crate) fn decouple_v_models(
                     JSXAttrName::Ident(quote_ident!("v-model"))
                 },
                 value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
-                    span: DUMMY_SP,
-                    expr: JSXExpr::Expr(Box::new(Expr::Array(ArrayLit {
-                        span: DUMMY_SP,
-                        elems,
-                    }))),
+                    span,
+                    expr: JSXExpr::Expr(Box::new(Expr::Array(ArrayLit { span, elems }))),
                 })),
             })
         })
@@ -285,3 +322,183 @@ pub(crate) fn transform_text(text: &str) -> String {
     }
     lines.join(" ")
 }
+
+/// Check if the expression can be the left side of assignment.
+pub(crate) fn is_assignable(expr: &Expr) -> bool {
+    match expr {
+        Expr::Ident(..) | Expr::Member(..) | Expr::SuperProp(..) => true,
+        Expr::Paren(ParenExpr { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+        | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => is_assignable(expr),
+        _ => false,
+    }
+}
+
+/// Convert PascalCase or camelCase name to kebab-case, like `KeepAlive` to `keep-alive`.
+pub(crate) fn hyphenate(name: &str) -> String {
+    let mut hyphenated = String::with_capacity(name.len() + 2);
+    for (index, c) in name.char_indices() {
+        if c.is_ascii_uppercase() {
+            if index > 0 {
+                hyphenated.push('-');
+            }
+            hyphenated.push(c.to_ascii_lowercase());
+        } else {
+            hyphenated.push(c);
+        }
+    }
+    hyphenated
+}
+
+/// Check if the tag refers to the built-in component, in either PascalCase or kebab-case.
+pub(crate) fn is_built_in_type(tag: &str, expected: &str) -> bool {
+    tag == expected || tag == hyphenate(expected)
+}
+
+/// Find the render function returned from `setup` of component options
+/// or the setup function passed to `defineComponent`.
+pub(crate) fn find_setup_render(call_expr: &mut CallExpr) -> Option<&mut ArrowExpr> {
+    let Some(ExprOrSpread { spread: None, expr }) = call_expr.args.first_mut() else {
+        return None;
+    };
+    match &mut **expr {
+        Expr::Object(ObjectLit { props, .. }) => props.iter_mut().find_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &mut **prop {
+                Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(key),
+                    value,
+                }) if key.sym == "setup" => find_render_in_setup(value),
+                Prop::Method(MethodProp {
+                    key: PropName::Ident(key),
+                    function,
+                }) if key.sym == "setup" => function
+                    .body
+                    .as_mut()
+                    .and_then(|body| find_returned_render(&mut body.stmts)),
+                _ => None,
+            },
+            PropOrSpread::Spread(..) => None,
+        }),
+        setup => find_render_in_setup(setup),
+    }
+}
+
+fn find_render_in_setup(setup: &mut Expr) -> Option<&mut ArrowExpr> {
+    match setup {
+        Expr::Arrow(ArrowExpr { body, .. }) => match &mut **body {
+            BlockStmtOrExpr::Expr(expr) => match &mut **expr {
+                Expr::Arrow(render) => Some(render),
+                _ => None,
+            },
+            BlockStmtOrExpr::BlockStmt(BlockStmt { stmts, .. }) => find_returned_render(stmts),
+        },
+        Expr::Fn(FnExpr { function, .. }) => function
+            .body
+            .as_mut()
+            .and_then(|body| find_returned_render(&mut body.stmts)),
+        _ => None,
+    }
+}
+
+/// Find the setup context, which is the second parameter of `setup`.
+pub(crate) fn find_setup_context(call_expr: &CallExpr) -> Option<&Pat> {
+    let Some(ExprOrSpread { spread: None, expr }) = call_expr.args.first() else {
+        return None;
+    };
+    match &**expr {
+        Expr::Object(ObjectLit { props, .. }) => props.iter().find_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(key),
+                    value,
+                }) if key.sym == "setup" => find_context_in_setup(value),
+                Prop::Method(MethodProp {
+                    key: PropName::Ident(key),
+                    function,
+                }) if key.sym == "setup" => function.params.get(1).map(|param| &param.pat),
+                _ => None,
+            },
+            PropOrSpread::Spread(..) => None,
+        }),
+        setup => find_context_in_setup(setup),
+    }
+}
+
+fn find_context_in_setup(setup: &Expr) -> Option<&Pat> {
+    match setup {
+        Expr::Arrow(ArrowExpr { params, .. }) => params.get(1),
+        Expr::Fn(FnExpr { function, .. }) => function.params.get(1).map(|param| &param.pat),
+        _ => None,
+    }
+}
+
+fn find_returned_render(stmts: &mut [Stmt]) -> Option<&mut ArrowExpr> {
+    match stmts.last_mut() {
+        Some(Stmt::Return(ReturnStmt { arg: Some(arg), .. })) => match &mut **arg {
+            Expr::Arrow(render) => Some(render),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Build `const { imported: local } = init`.
+pub(crate) fn build_destructuring(
+    specifiers: impl Iterator<Item = (&'static str, Ident)>,
+    init: Expr,
+) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Object(ObjectPat {
+                span: DUMMY_SP,
+                props: specifiers
+                    .map(|(imported, local)| {
+                        ObjectPatProp::KeyValue(KeyValuePatProp {
+                            key: PropName::Ident(quote_ident!(imported)),
+                            value: Box::new(Pat::Ident(local.into())),
+                        })
+                    })
+                    .collect(),
+                optional: false,
+                type_ann: None,
+            }),
+            init: Some(Box::new(init)),
+            definite: false,
+        }],
+    })))
+}
+
+/// Build access to global variable like `Vue` or `window.Vue`.
+pub(crate) fn build_global_access(path: &str) -> Expr {
+    let mut segments = path.split('.');
+    let root = Expr::Ident(quote_ident!(segments.next().unwrap_or_default()));
+    segments.fold(root, |obj, prop| {
+        Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(obj),
+            prop: MemberProp::Ident(quote_ident!(prop)),
+        })
+    })
+}
+
+/// Check if the expression accesses global variable like `Vue` or `window.Vue`.
+pub(crate) fn is_global_access(expr: &Expr, path: &str) -> bool {
+    match (expr, path.rsplit_once('.')) {
+        (Expr::Ident(ident), None) => ident.sym == path,
+        (
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }),
+            Some((obj_path, prop_name)),
+        ) => prop.sym == prop_name && is_global_access(obj, obj_path),
+        _ => false,
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    diagnostics::Diagnostic, has_key, patch_flags::PatchFlags, BlockType, VueJsxTransformVisitor,
+    FRAGMENT,
+};
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{ast::*, utils::quote_str},
+};
+
+/// Find the index of `v-for` attribute of the element.
+pub(crate) fn find_v_for(jsx_element: &JSXElement) -> Option<usize> {
+    jsx_element
+        .opening
+        .attrs
+        .iter()
+        .position(|jsx_attr_or_spread| {
+            matches!(
+                jsx_attr_or_spread,
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(ident),
+                    ..
+                }) if ident.sym == "v-for"
+            )
+        })
+}
+
+impl<C> VueJsxTransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Expand element with `v-for={item in list}` to a fragment
+    /// whose children are rendered by `renderList`.
+    /// Key of the fragment is given when it's a branch of `v-if`.
+    pub(crate) fn transform_v_for(
+        &mut self,
+        jsx_element: &JSXElement,
+        index: usize,
+        fragment_key: Option<usize>,
+    ) -> Expr {
+        let mut jsx_element = jsx_element.clone();
+        let JSXAttrOrSpread::JSXAttr(jsx_attr) = jsx_element.opening.attrs.remove(index) else {
+            unreachable!()
+        };
+        let Some((params, source)) = parse_v_for(&jsx_attr) else {
+            Diagnostic::InvalidVFor.emit(jsx_attr.span, &self.options);
+            return self.transform_jsx_element(&jsx_element, BlockType::None);
+        };
+
+        let memo = self.take_v_memo(&mut jsx_element);
+        let use_block =
+            self.options.optimize && self.pragma.is_none() && self.options.pragma.is_none();
+        // each item is a block, since the fragment doesn't track its dynamic descendants
+        let item_block_type = if use_block && self.is_stable_element(&jsx_element) {
+            BlockType::Stable
+        } else {
+            BlockType::None
+        };
+        let item = self.transform_jsx_element(&jsx_element, item_block_type);
+
+        let mut render_list_args = vec![ExprOrSpread {
+            spread: None,
+            expr: source,
+        }];
+        if let Some((deps, cache, index)) = memo {
+            let key = find_key_expr(&jsx_element);
+            let mut render_item = self.build_memo_render_item(params, item, key, *deps);
+            render_item.span = jsx_attr.span;
+            render_list_args.extend([
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Arrow(render_item)),
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Ident(cache)),
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Num((index as f64).into()))),
+                },
+            ]);
+        } else {
+            render_list_args.push(ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Arrow(ArrowExpr {
+                    span: jsx_attr.span,
+                    params,
+                    body: Box::new(BlockStmtOrExpr::Expr(Box::new(item))),
+                    is_async: false,
+                    is_generator: false,
+                    type_params: None,
+                    return_type: None,
+                })),
+            });
+        }
+        let render_list_call = Expr::Call(CallExpr {
+            span: jsx_attr.span,
+            callee: Callee::Expr(Box::new(Expr::Ident(self.import_from_vue("renderList")))),
+            args: render_list_args,
+            type_args: None,
+        });
+
+        let mut args = vec![
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Ident(self.import_from_vue(FRAGMENT))),
+            },
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(match fragment_key {
+                    Some(key) => Expr::Object(ObjectLit {
+                        span: DUMMY_SP,
+                        props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Str(quote_str!("key")),
+                            value: Box::new(Expr::Lit(Lit::Num((key as f64).into()))),
+                        })))],
+                    }),
+                    None => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+                }),
+            },
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(render_list_call),
+            },
+        ];
+        // custom pragma may not accept patch flag
+        if self.pragma.is_none() && self.options.pragma.is_none() {
+            let patch_flag = if has_key(&jsx_element) {
+                PatchFlags::KEYED_FRAGMENT
+            } else {
+                PatchFlags::UNKEYED_FRAGMENT
+            };
+            args.push(ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Lit(Lit::Num(Number {
+                    span: DUMMY_SP,
+                    value: patch_flag.bits() as f64,
+                    raw: None,
+                }))),
+            });
+        }
+
+        if use_block {
+            let create_block_call = Expr::Call(CallExpr {
+                span: jsx_element.span,
+                callee: Callee::Expr(Box::new(Expr::Ident(
+                    self.import_from_vue("createElementBlock"),
+                ))),
+                args,
+                type_args: None,
+            });
+            self.wrap_block(create_block_call, true)
+        } else {
+            Expr::Call(CallExpr {
+                span: jsx_element.span,
+                callee: Callee::Expr(Box::new(Expr::Ident(self.get_pragma()))),
+                args,
+                type_args: None,
+            })
+        }
+    }
+}
+
+/// Parse `item in list`, `[item, index] in list` or `(item, index) in list`
+/// into parameters of callback and the source list.
+fn parse_v_for(jsx_attr: &JSXAttr) -> Option<(Vec<Pat>, Box<Expr>)> {
+    let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(expr),
+        ..
+    })) = &jsx_attr.value
+    else {
+        return None;
+    };
+    let Expr::Bin(BinExpr {
+        op: op!("in"),
+        left,
+        right,
+        ..
+    }) = &**expr
+    else {
+        return None;
+    };
+
+    let aliases = match &**left {
+        Expr::Ident(ident) => vec![ident],
+        Expr::Array(ArrayLit { elems, .. }) => elems
+            .iter()
+            .map(|elem| match elem {
+                Some(ExprOrSpread { spread: None, expr }) => expr.as_ident(),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?,
+        Expr::Paren(ParenExpr { expr, .. }) => match &**expr {
+            Expr::Ident(ident) => vec![ident],
+            Expr::Seq(SeqExpr { exprs, .. }) => exprs
+                .iter()
+                .map(|expr| expr.as_ident())
+                .collect::<Option<Vec<_>>>()?,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    if aliases.is_empty() {
+        return None;
+    }
+
+    let params = aliases
+        .into_iter()
+        .map(|ident| Pat::Ident(ident.clone().into()))
+        .collect();
+    Some((params, right.clone()))
+}
+
+/// Get value of `key` attribute, which is compared with key of the cached item.
+fn find_key_expr(jsx_element: &JSXElement) -> Option<Box<Expr>> {
+    jsx_element
+        .opening
+        .attrs
+        .iter()
+        .find_map(|jsx_attr_or_spread| match jsx_attr_or_spread {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(ident),
+                value: Some(value),
+                ..
+            }) if ident.sym == "key" => match value {
+                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) => Some(expr.clone()),
+                JSXAttrValue::Lit(lit) => Some(Box::new(Expr::Lit(lit.clone()))),
+                _ => None,
+            },
+            _ => None,
+        })
+}
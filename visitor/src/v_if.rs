@@ -0,0 +1,227 @@
+use crate::{
+    diagnostics::Diagnostic, has_key, render_cache, v_for, BlockType, VueJsxTransformVisitor,
+};
+use std::mem;
+use swc_core::{
+    common::{comments::Comments, Spanned, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{quote_ident, quote_str},
+    },
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Condition {
+    If,
+    ElseIf,
+    Else,
+}
+
+/// Child of element, or elements of `v-if`, `v-else-if` and `v-else` which are next to each other.
+pub(crate) enum ChildOrVIfChain<'a> {
+    Child(&'a JSXElementChild),
+    VIfChain(Vec<&'a JSXElement>),
+}
+
+/// Find the conditional directive of the element and its index.
+pub(crate) fn find_condition(jsx_element: &JSXElement) -> Option<(usize, Condition)> {
+    jsx_element
+        .opening
+        .attrs
+        .iter()
+        .enumerate()
+        .find_map(|(index, jsx_attr_or_spread)| match jsx_attr_or_spread {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(ident),
+                ..
+            }) => match &*ident.sym {
+                "v-if" | "vIf" => Some((index, Condition::If)),
+                "v-else-if" | "vElseIf" => Some((index, Condition::ElseIf)),
+                "v-else" | "vElse" => Some((index, Condition::Else)),
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+pub(crate) fn group_v_if_chains(children: &[JSXElementChild]) -> Vec<ChildOrVIfChain<'_>> {
+    let mut groups = Vec::with_capacity(children.len());
+    let mut iter = children.iter().peekable();
+    while let Some(child) = iter.next() {
+        let JSXElementChild::JSXElement(jsx_element) = child else {
+            groups.push(ChildOrVIfChain::Child(child));
+            continue;
+        };
+        if !matches!(find_condition(jsx_element), Some((_, Condition::If))) {
+            groups.push(ChildOrVIfChain::Child(child));
+            continue;
+        }
+
+        let mut chain = vec![&**jsx_element];
+        loop {
+            // whitespaces and comments between branches are dropped
+            let mut lookahead = iter.clone();
+            let next = lookahead.find(|child| !is_blank(child));
+            let Some(JSXElementChild::JSXElement(jsx_element)) = next else {
+                break;
+            };
+            let condition = find_condition(jsx_element).map(|(_, condition)| condition);
+            if !matches!(condition, Some(Condition::ElseIf | Condition::Else)) {
+                break;
+            }
+            chain.push(jsx_element);
+            iter = lookahead;
+            if condition == Some(Condition::Else) {
+                break;
+            }
+        }
+        groups.push(ChildOrVIfChain::VIfChain(chain));
+    }
+    groups
+}
+
+fn is_blank(child: &JSXElementChild) -> bool {
+    match child {
+        JSXElementChild::JSXText(jsx_text) => jsx_text.value.trim().is_empty(),
+        JSXElementChild::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::JSXEmptyExpr(..),
+            ..
+        }) => true,
+        _ => false,
+    }
+}
+
+impl<C> VueJsxTransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Transform elements of `v-if` chain to nested conditional expressions.
+    /// Each branch has distinct key, so it won't be patched from another branch.
+    ///
+    /// `key` is shared by chains of the same parent.
+    pub(crate) fn transform_v_if_chain(&mut self, chain: &[&JSXElement], key: &mut usize) -> Expr {
+        let use_block =
+            self.options.optimize && self.pragma.is_none() && self.options.pragma.is_none();
+
+        let branches = chain
+            .iter()
+            .map(|jsx_element| {
+                let mut jsx_element = (*jsx_element).clone();
+                let test = find_condition(&jsx_element).map(|(index, condition)| {
+                    let JSXAttrOrSpread::JSXAttr(jsx_attr) =
+                        jsx_element.opening.attrs.remove(index)
+                    else {
+                        unreachable!()
+                    };
+                    (jsx_attr, condition)
+                });
+                let test = match test {
+                    Some((_, Condition::Else)) | None => None,
+                    Some((jsx_attr, ..)) => Some(match jsx_attr.value {
+                        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            expr: JSXExpr::Expr(expr),
+                            ..
+                        })) => expr,
+                        _ => {
+                            Diagnostic::VIfWithoutExpr.emit(jsx_attr.span, &self.options);
+                            Box::new(Expr::Lit(Lit::Bool(Bool {
+                                span: DUMMY_SP,
+                                value: true,
+                            })))
+                        }
+                    }),
+                };
+
+                // list rendered by `v-for` is a fragment, so key is added to the fragment instead
+                let v_for = v_for::find_v_for(&jsx_element);
+                if !has_key(&jsx_element) && v_for.is_none() {
+                    jsx_element.opening.attrs.insert(
+                        0,
+                        JSXAttrOrSpread::JSXAttr(JSXAttr {
+                            span: DUMMY_SP,
+                            name: JSXAttrName::Ident(quote_ident!("key")),
+                            value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                                span: DUMMY_SP,
+                                expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Num(Number {
+                                    span: DUMMY_SP,
+                                    value: *key as f64,
+                                    raw: None,
+                                })))),
+                            })),
+                        }),
+                    );
+                }
+                let branch_key = *key;
+                *key += 1;
+
+                if let Some(index) = v_for {
+                    // static items can't be hoisted, otherwise they won't be in the block
+                    let hoisting = mem::replace(&mut self.hoisting, true);
+                    let vnode = match render_cache::find_v_once(&jsx_element) {
+                        Some(once) => self.transform_v_once(
+                            &jsx_element,
+                            once,
+                            BlockType::None,
+                            Some(branch_key),
+                        ),
+                        None => self.transform_v_for(&jsx_element, index, Some(branch_key)),
+                    };
+                    self.hoisting = hoisting;
+                    return (test, vnode);
+                }
+                // each branch is a block, so the parent can still be a stable block
+                if use_block && self.is_stable_element(&jsx_element) {
+                    // static branch can't be hoisted, otherwise it won't be a block
+                    let hoisting = mem::replace(&mut self.hoisting, true);
+                    let vnode = self.transform_jsx_element(&jsx_element, BlockType::Stable);
+                    self.hoisting = hoisting;
+                    (test, vnode)
+                } else {
+                    (
+                        test,
+                        self.transform_jsx_element(&jsx_element, BlockType::None),
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut branches = branches.into_iter().rev();
+        let alt = match branches.next() {
+            Some((None, cons)) => cons,
+            last => {
+                let comment = Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: Callee::Expr(Box::new(Expr::Ident(
+                        self.import_from_vue("createCommentVNode"),
+                    ))),
+                    args: vec![
+                        Expr::Lit(Lit::Str(quote_str!("v-if"))).into(),
+                        Expr::Lit(Lit::Bool(Bool {
+                            span: DUMMY_SP,
+                            value: true,
+                        }))
+                        .into(),
+                    ],
+                    type_args: None,
+                });
+                match last {
+                    Some((Some(test), cons)) => build_cond(test, cons, comment),
+                    _ => comment,
+                }
+            }
+        };
+        branches.fold(alt, |alt, (test, cons)| match test {
+            Some(test) => build_cond(test, cons, alt),
+            None => cons,
+        })
+    }
+}
+
+fn build_cond(test: Box<Expr>, cons: Expr, alt: Expr) -> Expr {
+    Expr::Cond(CondExpr {
+        span: cons.span(),
+        test,
+        cons: Box::new(cons),
+        alt: Box::new(alt),
+    })
+}
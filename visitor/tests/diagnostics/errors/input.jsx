@@ -0,0 +1,9 @@
+const App = () => (
+  <div>
+    <p v-if="ok">yes</p>
+    <li v-for={items}>{item}</li>
+    <input v-model_trimm={state.name} />
+    <textarea v-model_lazy_number_tirm={state.bio} />
+    <input v-model={state?.name} />
+  </div>
+)
@@ -0,0 +1,9 @@
+const App = () => (
+  <div>
+    <Comp v-slots={getSlots()} />
+    <input type={type} v-model={state.value} />
+    <input v-model={"text"} />
+  </div>
+)
+
+const cached = <p v-once>{msg}</p>
@@ -1,25 +1,35 @@
-use std::{fs, io::ErrorKind, path::PathBuf};
+use std::{
+    fmt::Write,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 use swc_core::{
-    common::{chain, Mark},
+    common::{
+        chain,
+        errors::{DiagnosticBuilder, DiagnosticId, Emitter, Handler, HandlerFlags, HANDLER},
+        Mark,
+    },
     ecma::{
+        ast::{Module, ModuleItem, Program},
         parser::{EsConfig, Syntax, TsConfig},
-        transforms::{base::resolver, testing::test_fixture},
-        visit::as_folder,
+        transforms::{
+            base::{fixer::fixer, hygiene::hygiene, resolver},
+            testing::{test_fixture, FixtureTestConfig, Tester},
+        },
+        visit::{as_folder, FoldWith},
     },
 };
 use swc_vue_jsx_visitor::{Options, VueJsxTransformVisitor};
+use testing::NormalizedOutput;
 
+/// Source map is compared with `output.map` if it exists,
+/// which checks that generated code is mapped to the original JSX.
 #[testing::fixture("tests/fixture/**/input.jsx")]
 #[testing::fixture("tests/fixture/**/input.tsx")]
 fn test(input: PathBuf) {
-    let config = match fs::read_to_string(input.with_file_name("config.json")) {
-        Ok(json) => serde_json::from_str(&json).unwrap(),
-        Err(err) if err.kind() == ErrorKind::NotFound => Options {
-            optimize: true,
-            ..Default::default()
-        },
-        Err(err) => panic!("failed to read `config.json`: {err}"),
-    };
+    let config = read_config(&input);
     let output = input.with_file_name("output.js");
 
     let is_ts = input
@@ -44,15 +54,137 @@ fn test(input: PathBuf) {
             let unresolved_mark = Mark::new();
             chain!(
                 resolver(unresolved_mark, Mark::new(), is_ts),
-                as_folder(VueJsxTransformVisitor::new(
-                    config.clone(),
-                    unresolved_mark,
-                    Some(tester.comments.clone())
-                ))
+                as_folder(
+                    VueJsxTransformVisitor::new(
+                        config.clone(),
+                        unresolved_mark,
+                        Some(tester.comments.clone())
+                    )
+                    .with_filename(&input)
+                )
             )
         },
         &input,
         &output,
-        Default::default(),
+        FixtureTestConfig {
+            sourcemap: output.with_extension("map").exists(),
+            ..Default::default()
+        },
     )
 }
+
+/// Scripts can't be parsed by `test_fixture`, so they're transformed and printed manually.
+#[testing::fixture("tests/fixture/**/input.cjs")]
+fn test_script(input: PathBuf) {
+    let config = read_config(&input);
+    let src = fs::read_to_string(&input).unwrap();
+
+    let actual = Tester::run(|tester| {
+        let script = tester.with_parser(
+            "input.cjs",
+            Syntax::Es(EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            &src,
+            |parser| parser.parse_script(),
+        )?;
+        let unresolved_mark = Mark::new();
+        let script = Program::Script(script)
+            .fold_with(&mut chain!(
+                resolver(unresolved_mark, Mark::new(), false),
+                as_folder(VueJsxTransformVisitor::new(
+                    config,
+                    unresolved_mark,
+                    Some(tester.comments.clone())
+                )),
+                hygiene(),
+                fixer(Some(&tester.comments))
+            ))
+            .expect_script();
+        // statements of script are printed in the same way as module
+        let module = Module {
+            span: script.span,
+            body: script.body.into_iter().map(ModuleItem::Stmt).collect(),
+            shebang: None,
+        };
+        Ok(tester.print(&module, &tester.comments.clone()))
+    });
+
+    NormalizedOutput::from(actual)
+        .compare_to_file(input.with_file_name("output.cjs"))
+        .unwrap();
+}
+
+/// Warnings aren't captured by `test_fixture`,
+/// so diagnostics are collected with their severities, codes and help notes.
+#[testing::fixture("tests/diagnostics/**/input.jsx")]
+fn test_diagnostics(input: PathBuf) {
+    let config = read_config(&input);
+    let src = fs::read_to_string(&input).unwrap();
+    let diagnostics = Arc::new(Mutex::new(String::new()));
+
+    Tester::run(|tester| {
+        let module = tester.with_parser(
+            "input.jsx",
+            Syntax::Es(EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            &src,
+            |parser| parser.parse_module(),
+        )?;
+        let handler = Handler::with_emitter_and_flags(
+            Box::new(DiagnosticsCollector(diagnostics.clone())),
+            HandlerFlags {
+                can_emit_warnings: true,
+                ..Default::default()
+            },
+        );
+        let unresolved_mark = Mark::new();
+        HANDLER.set(&handler, || {
+            Program::Module(module).fold_with(&mut chain!(
+                resolver(unresolved_mark, Mark::new(), false),
+                as_folder(VueJsxTransformVisitor::new(
+                    config,
+                    unresolved_mark,
+                    Some(tester.comments.clone())
+                ))
+            ))
+        });
+        Ok(())
+    });
+
+    let diagnostics = diagnostics.lock().unwrap().clone();
+    NormalizedOutput::from(diagnostics)
+        .compare_to_file(input.with_file_name("output.txt"))
+        .unwrap();
+}
+
+struct DiagnosticsCollector(Arc<Mutex<String>>);
+
+impl Emitter for DiagnosticsCollector {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        let mut output = self.0.lock().unwrap();
+        let code = match &db.code {
+            Some(DiagnosticId::Error(code)) => format!("error code {code}"),
+            Some(DiagnosticId::Lint(code)) => format!("lint code {code}"),
+            None => "no code".into(),
+        };
+        writeln!(output, "{}: {} ({code})", db.level, db.message()).unwrap();
+        for child in &db.children {
+            writeln!(output, "  {}: {}", child.level, child.message()).unwrap();
+        }
+    }
+}
+
+fn read_config(input: &Path) -> Options {
+    match fs::read_to_string(input.with_file_name("config.json")) {
+        Ok(json) => serde_json::from_str(&json).unwrap(),
+        Err(err) if err.kind() == ErrorKind::NotFound => Options {
+            optimize: true,
+            ..Default::default()
+        },
+        Err(err) => panic!("failed to read `config.json`: {err}"),
+    }
+}
@@ -0,0 +1,28 @@
+const element = () => <div class={cls}><span title={title}>text</span></div>;
+
+const component = () => <Comp foo={foo}><p>slot</p></Comp>;
+
+const fragment = () => (
+  <>
+    <h1 class={cls}>title</h1>
+    <p>paragraph</p>
+  </>
+);
+
+const keyedFragment = () => (
+  <>
+    {header}
+    <li key="a" class={a}>a</li>
+    <li key="b" class={b}>b</li>
+  </>
+);
+
+const unkeyedFragment = () => <>{list.map((item) => <li class={item}>{item}</li>)}</>;
+
+const dynamicChildren = () => <div class={cls}>{msg}</div>;
+
+const jsxInAttrs = () => <Comp icon={<Icon class={cls} />} />;
+
+const callInAttrs = () => <div class={normalizeClass(cls)} onClick={() => emit('click')} />;
+
+const lazyJsxInAttrs = () => <Comp renderIcon={() => <Icon />} />;
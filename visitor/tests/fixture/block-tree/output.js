@@ -0,0 +1,94 @@
+import { Fragment as _Fragment, createBlock as _createBlock, createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent } from "vue";
+const _hoisted_1 = _createVNode("p", null, [
+    _createTextVNode("slot")
+], -1), _hoisted_2 = _createVNode("p", null, [
+    _createTextVNode("paragraph")
+], -1);
+const element = ()=>(_openBlock(), _createElementBlock("div", {
+        "class": cls
+    }, [
+        _createVNode("span", {
+            "title": title
+        }, [
+            _createTextVNode("text")
+        ], 8, [
+            "title"
+        ])
+    ], 2));
+const component = ()=>{
+    const _component_Comp = _resolveComponent("Comp");
+    return _openBlock(), _createBlock(_component_Comp, {
+        "foo": foo
+    }, {
+        default: ()=>[
+                _hoisted_1
+            ],
+        _: 1
+    }, 8, [
+        "foo"
+    ]);
+};
+const fragment = ()=>(_openBlock(), _createElementBlock(_Fragment, null, [
+        _createVNode("h1", {
+            "class": cls
+        }, [
+            _createTextVNode("title")
+        ], 2),
+        _hoisted_2
+    ], 64));
+const keyedFragment = ()=>(_openBlock(true), _createElementBlock(_Fragment, null, [
+        header,
+        _createVNode("li", {
+            "key": "a",
+            "class": a
+        }, [
+            _createTextVNode("a")
+        ], 2),
+        _createVNode("li", {
+            "key": "b",
+            "class": b
+        }, [
+            _createTextVNode("b")
+        ], 2)
+    ], 128));
+const unkeyedFragment = ()=>(_openBlock(true), _createElementBlock(_Fragment, null, [
+        list.map((item)=>_createVNode("li", {
+                "class": item
+            }, [
+                item
+            ], 2))
+    ], 256));
+const dynamicChildren = ()=>_createVNode("div", {
+        "class": cls
+    }, [
+        msg
+    ], 2);
+const jsxInAttrs = ()=>{
+    const _component_Icon = _resolveComponent("Icon"), _component_Comp = _resolveComponent("Comp");
+    return _createVNode(_component_Comp, {
+        "icon": (_openBlock(), _createBlock(_component_Icon, {
+            "class": cls
+        }, null, 8, [
+            "class"
+        ]))
+    }, null, 8, [
+        "icon"
+    ]);
+};
+const callInAttrs = ()=>_createVNode("div", {
+        "class": normalizeClass(cls),
+        "onClick": ()=>emit('click')
+    }, null, 10, [
+        "onClick"
+    ]);
+const lazyJsxInAttrs = ()=>{
+    const _component_Comp = _resolveComponent("Comp");
+    return _openBlock(), _createBlock(_component_Comp, {
+        "renderIcon": ()=>{
+            const _component_Icon = _resolveComponent("Icon");
+            return _openBlock(), _createBlock(_component_Icon, null, null);
+        }
+    }, null, 8, [
+        "renderIcon"
+    ]);
+};
@@ -0,0 +1,41 @@
+import { Transition as Fade, KeepAlive as Cache } from 'vue'
+import { Teleport as Portal } from './portal'
+
+const Modal = () => (
+  <div>
+    <Teleport to="body">
+      <div class="modal">{msg}</div>
+    </Teleport>
+    <Suspense>
+      <AsyncComp />
+    </Suspense>
+    <Transition name="fade">
+      <p>{msg}</p>
+    </Transition>
+    <Fade>
+      <p>hello</p>
+    </Fade>
+    <TransitionGroup tag="ul">
+      <li key="a">a</li>
+    </TransitionGroup>
+    <KeepAlive>
+      <Comp />
+    </KeepAlive>
+  </div>
+)
+
+const Declared = () => {
+  const Teleport = () => null
+  return <Teleport>local</Teleport>
+}
+
+const Imported = () => (
+  <div>
+    <Cache>
+      <Comp />
+    </Cache>
+    <Portal>
+      <Comp />
+    </Portal>
+  </div>
+)
@@ -0,0 +1,79 @@
+import { Transition as Fade, KeepAlive as Cache, Suspense as _Suspense, Teleport as _Teleport, TransitionGroup as _TransitionGroup, createBlock as _createBlock, createTextVNode as _createTextVNode, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent } from 'vue';
+import { Teleport as Portal } from './portal';
+const _hoisted_1 = _createVNode("p", null, [
+    _createTextVNode("hello")
+], -1), _hoisted_2 = _createVNode("li", {
+    "key": "a"
+}, [
+    _createTextVNode("a")
+], -1);
+const Modal = ()=>{
+    const _component_AsyncComp = _resolveComponent("AsyncComp"), _component_Comp = _resolveComponent("Comp");
+    return _createVNode("div", null, [
+        _createVNode(_Teleport, {
+            "to": "body"
+        }, [
+            _createVNode("div", {
+                "class": "modal"
+            }, [
+                msg
+            ])
+        ]),
+        _createVNode(_Suspense, null, {
+            default: ()=>[
+                    _createVNode(_component_AsyncComp, null, null)
+                ],
+            _: 1
+        }),
+        _createVNode(Fade, {
+            "name": "fade"
+        }, {
+            default: ()=>[
+                    _createVNode("p", null, [
+                        msg
+                    ])
+                ],
+            _: 1
+        }),
+        _createVNode(Fade, null, {
+            default: ()=>[
+                    _hoisted_1
+                ],
+            _: 1
+        }),
+        _createVNode(_TransitionGroup, {
+            "tag": "ul"
+        }, {
+            default: ()=>[
+                    _hoisted_2
+                ],
+            _: 1
+        }),
+        _createVNode(Cache, null, [
+            _createVNode(_component_Comp, null, null)
+        ])
+    ]);
+};
+const Declared = ()=>{
+    const Teleport = ()=>null;
+    return _openBlock(), _createBlock(Teleport, null, {
+        default: ()=>[
+                _createTextVNode("local")
+            ],
+        _: 1
+    });
+};
+const Imported = ()=>{
+    const _component_Comp = _resolveComponent("Comp");
+    return _createVNode("div", null, [
+        _createVNode(Cache, null, [
+            _createVNode(_component_Comp, null, null)
+        ]),
+        _createVNode(Portal, null, {
+            default: ()=>[
+                    _createVNode(_component_Comp, null, null)
+                ],
+            _: 1
+        })
+    ]);
+};
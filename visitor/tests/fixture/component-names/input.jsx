@@ -0,0 +1,16 @@
+const App = () => (
+  <div>
+    <my-widget title="a" />
+    <MyWidget title="b" />
+    <ui:button type="primary">OK</ui:button>
+    <local:item />
+    <icons:arrow-left />
+    <ext:formula />
+    <keep-alive>
+      <router-view />
+    </keep-alive>
+    <transition name="fade">
+      <p>content</p>
+    </transition>
+  </div>
+)
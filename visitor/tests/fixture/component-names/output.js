@@ -0,0 +1,37 @@
+import { KeepAlive as _KeepAlive, Transition as _Transition, createTextVNode as _createTextVNode, createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
+const _hoisted_1 = _createVNode("p", null, [
+    _createTextVNode("content")
+], -1);
+const App = ()=>{
+    const _component_my_widget = _resolveComponent("my-widget"), _component_MyWidget = _resolveComponent("MyWidget"), _component_el_plus_button = _resolveComponent("el-plus-button"), _component_item = _resolveComponent("item"), _component_icons_arrow_left = _resolveComponent("icons-arrow-left"), _component_router_view = _resolveComponent("router-view");
+    return _createVNode("div", null, [
+        _createVNode(_component_my_widget, {
+            "title": "a"
+        }, null),
+        _createVNode(_component_MyWidget, {
+            "title": "b"
+        }, null),
+        _createVNode(_component_el_plus_button, {
+            "type": "primary"
+        }, {
+            default: ()=>[
+                    _createTextVNode("OK")
+                ],
+            _: 1
+        }),
+        _createVNode(_component_item, null, null),
+        _createVNode(_component_icons_arrow_left, null, null),
+        _createVNode("ext:formula", null, null),
+        _createVNode(_KeepAlive, null, [
+            _createVNode(_component_router_view, null, null)
+        ]),
+        _createVNode(_Transition, {
+            "name": "fade"
+        }, {
+            default: ()=>[
+                    _hoisted_1
+                ],
+            _: 1
+        })
+    ]);
+};
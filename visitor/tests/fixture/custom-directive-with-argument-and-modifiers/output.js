@@ -1,28 +1,67 @@
-import {
-  Fragment as _Fragment,
-  createVNode as _createVNode,
-  resolveComponent as _resolveComponent,
-  resolveDirective as _resolveDirective,
-  withDirectives as _withDirectives,
-} from "vue";
-_createVNode(_Fragment, null, [
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, 'y']]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, 'y', {
-    a: true,
-    b: true
-  }]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, void 0, {
-    a: true,
-    b: true
-  }]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, y, {
-    a: true,
-    b: true
-  }]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, y, {
-    a: true,
-    b: true
-  }]]),
-]);
+import { Fragment as _Fragment, createElementBlock as _createElementBlock, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent, resolveDirective as _resolveDirective, withDirectives as _withDirectives } from "vue";
+const _component_A = _resolveComponent("A"), _directive_xxx = _resolveDirective("xxx");
+_openBlock(), _createElementBlock(_Fragment, null, [
+    _withDirectives(_createVNode(_component_A, null, null, 512), [
+        [
+            _directive_xxx,
+            x
+        ]
+    ]),
+    _withDirectives(_createVNode(_component_A, null, null, 512), [
+        [
+            _directive_xxx,
+            x
+        ]
+    ]),
+    _withDirectives(_createVNode(_component_A, null, null, 512), [
+        [
+            _directive_xxx,
+            x,
+            'y'
+        ]
+    ]),
+    _withDirectives(_createVNode(_component_A, null, null, 512), [
+        [
+            _directive_xxx,
+            x,
+            'y',
+            {
+                a: true,
+                b: true
+            }
+        ]
+    ]),
+    _withDirectives(_createVNode(_component_A, null, null, 512), [
+        [
+            _directive_xxx,
+            x,
+            void 0,
+            {
+                a: true,
+                b: true
+            }
+        ]
+    ]),
+    _withDirectives(_createVNode(_component_A, null, null, 512), [
+        [
+            _directive_xxx,
+            x,
+            y,
+            {
+                a: true,
+                b: true
+            }
+        ]
+    ]),
+    _withDirectives(_createVNode(_component_A, null, null, 512), [
+        [
+            _directive_xxx,
+            x,
+            y,
+            {
+                a: true,
+                b: true
+            }
+        ]
+    ])
+], 64);
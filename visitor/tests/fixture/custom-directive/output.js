@@ -1,7 +1,8 @@
-import {
-  createVNode as _createVNode,
-  resolveComponent as _resolveComponent,
-  resolveDirective as _resolveDirective,
-  withDirectives as _withDirectives,
-} from "vue";
-_withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("cus"), x]]);
+import { createBlock as _createBlock, openBlock as _openBlock, resolveComponent as _resolveComponent, resolveDirective as _resolveDirective, withDirectives as _withDirectives } from "vue";
+const _component_A = _resolveComponent("A"), _directive_cus = _resolveDirective("cus");
+_withDirectives((_openBlock(), _createBlock(_component_A, null, null, 512)), [
+    [
+        _directive_cus,
+        x
+    ]
+]);
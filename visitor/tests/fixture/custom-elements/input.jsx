@@ -0,0 +1,12 @@
+const App = () => (
+  <div>
+    <model-viewer src={src} />
+    <ds-button>OK</ds-button>
+    <ds-vue-table data={rows} />
+    <line-chart data={points} />
+    <x-a />
+    <x-ab />
+    <i-icon />
+    <i-vue />
+  </div>
+)
@@ -0,0 +1,29 @@
+import { createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent } from "vue";
+const _hoisted_1 = _createVNode("ds-button", null, [
+    _createTextVNode("OK")
+], -1), _hoisted_2 = _createVNode("x-a", null, null, -1), _hoisted_3 = _createVNode("i-icon", null, null, -1);
+const App = ()=>{
+    const _component_ds_vue_table = _resolveComponent("ds-vue-table"), _component_x_ab = _resolveComponent("x-ab"), _component_i_vue = _resolveComponent("i-vue");
+    return _openBlock(), _createElementBlock("div", null, [
+        _createVNode("model-viewer", {
+            "src": src
+        }, null, 8, [
+            "src"
+        ]),
+        _hoisted_1,
+        _createVNode(_component_ds_vue_table, {
+            "data": rows
+        }, null, 8, [
+            "data"
+        ]),
+        _createVNode("line-chart", {
+            "data": points
+        }, null, 8, [
+            "data"
+        ]),
+        _hoisted_2,
+        _createVNode(_component_x_ab, null, null),
+        _hoisted_3,
+        _createVNode(_component_i_vue, null, null)
+    ]);
+};
@@ -1,5 +1,8 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("Badge"), null, {
-  default: () => [slots.default()],
-  _: 1
+const _component_Badge = _resolveComponent("Badge");
+_createVNode(_component_Badge, null, {
+    default: ()=>[
+            slots.default()
+        ],
+    _: 1
 });
@@ -0,0 +1,12 @@
+const Field = ({ schema }) => (
+  <form>
+    <component is={schema.component} class="field" value={schema.value}>
+      {schema.label}
+    </component>
+    <component is="input" />
+  </form>
+)
+
+const Root = () => <component is={Comp} />
+
+const Declared = (component) => <component is="a" />
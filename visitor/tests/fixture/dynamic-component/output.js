@@ -0,0 +1,17 @@
+import { createBlock as _createBlock, createVNode as _createVNode, openBlock as _openBlock, resolveDynamicComponent as _resolveDynamicComponent } from "vue";
+const Field = ({ schema })=>_createVNode("form", null, [
+        _createVNode(_resolveDynamicComponent(schema.component), {
+            "class": "field",
+            "value": schema.value
+        }, {
+            default: ()=>[
+                    schema.label
+                ],
+            _: 1
+        }, 16),
+        _createVNode(_resolveDynamicComponent("input"), null, null)
+    ]);
+const Root = ()=>(_openBlock(), _createBlock(_resolveDynamicComponent(Comp), null, null));
+const Declared = (component)=>(_openBlock(), _createBlock(component, {
+        "is": "a"
+    }, null));
@@ -1,2 +1,3 @@
 import { createVNode as _createVNode } from "vue";
-_createVNode("h1", { "title": "" }, null);
+const _hoisted_1 = _createVNode("h1", { "title": "" }, null, -1);
+_hoisted_1;
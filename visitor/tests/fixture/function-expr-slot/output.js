@@ -1,4 +1,5 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("A"), null, {
-  default: () => "foo"
+const _component_A = _resolveComponent("A");
+_createVNode(_component_A, null, {
+    default: ()=>"foo"
 });
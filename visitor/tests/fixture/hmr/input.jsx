@@ -0,0 +1,16 @@
+import { defineComponent } from 'vue'
+
+export const Foo = defineComponent(() => () => <div>foo</div>)
+
+const Bar = defineComponent(() => () => <div>bar</div>)
+
+const Local = defineComponent(() => () => <div>local</div>)
+
+export { Bar, Bar as Baz }
+
+export default defineComponent(() => () => (
+  <div>
+    <Foo />
+    <Local />
+  </div>
+))
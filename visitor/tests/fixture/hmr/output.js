@@ -0,0 +1,27 @@
+import { defineComponent, createTextVNode as _createTextVNode, createVNode as _createVNode } from 'vue';
+export const Foo = defineComponent(()=>()=>_createVNode("div", null, [
+            _createTextVNode("foo")
+        ]));
+const Bar = defineComponent(()=>()=>_createVNode("div", null, [
+            _createTextVNode("bar")
+        ]));
+const Local = defineComponent(()=>()=>_createVNode("div", null, [
+            _createTextVNode("local")
+        ]));
+export { Bar, Bar as Baz };
+const __default__ = defineComponent(()=>()=>_createVNode("div", null, [
+            _createVNode(Foo, null, null),
+            _createVNode(Local, null, null)
+        ]));
+export default __default__;
+Foo.__hmrId = "8bc0d0a8";
+__VUE_HMR_RUNTIME__.createRecord("8bc0d0a8", Foo);
+__default__.__hmrId = "565dbfcd";
+__VUE_HMR_RUNTIME__.createRecord("565dbfcd", __default__);
+Bar.__hmrId = "774aa5e5";
+__VUE_HMR_RUNTIME__.createRecord("774aa5e5", Bar);
+import.meta.hot.accept(({ Foo: __Foo, default: __default, Bar: __Bar })=>{
+    __VUE_HMR_RUNTIME__.reload("8bc0d0a8", __Foo);
+    __VUE_HMR_RUNTIME__.reload("565dbfcd", __default);
+    __VUE_HMR_RUNTIME__.reload("774aa5e5", __Bar);
+});
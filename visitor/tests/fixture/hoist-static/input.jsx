@@ -0,0 +1,14 @@
+const render = () => (
+  <div class="layout">
+    <header class="header">
+      <h1 title="Title">Static title</h1>
+      <nav>
+        <a href="/">Home</a>
+      </nav>
+    </header>
+    <main>{content}</main>
+    <span ref={foo}>ref</span>
+    <input disabled />
+    <Comp>static slot</Comp>
+  </div>
+);
@@ -0,0 +1,42 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
+const _hoisted_1 = _createVNode("header", {
+    "class": "header"
+}, [
+    _createVNode("h1", {
+        "title": "Title"
+    }, [
+        _createTextVNode("Static title")
+    ]),
+    _createVNode("nav", null, [
+        _createVNode("a", {
+            "href": "/"
+        }, [
+            _createTextVNode("Home")
+        ])
+    ])
+], -1), _hoisted_2 = _createVNode("input", {
+    "disabled": true
+}, null, -1);
+const render = ()=>{
+    const _component_Comp = _resolveComponent("Comp");
+    return _createVNode("div", {
+        "class": "layout"
+    }, [
+        _hoisted_1,
+        _createVNode("main", null, [
+            content
+        ]),
+        _createVNode("span", {
+            "ref": foo
+        }, [
+            _createTextVNode("ref")
+        ], 512),
+        _hoisted_2,
+        _createVNode(_component_Comp, null, {
+            default: ()=>[
+                    _createTextVNode("static slot")
+                ],
+            _: 1
+        })
+    ]);
+};
@@ -0,0 +1,2 @@
+const A = () => <Comp v-slots={getSlots()}>default</Comp>;
+const B = () => <input type={1} v-model={value} />;
@@ -0,0 +1,21 @@
+import { createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent, vModelDynamic as _vModelDynamic, withDirectives as _withDirectives } from "vue";
+const A = ()=>{
+    const _component_Comp = _resolveComponent("Comp");
+    return _createVNode(_component_Comp, null, {
+        default: ()=>[
+                _createTextVNode("default")
+            ],
+        _: 1
+    });
+};
+const B = ()=>_withDirectives((_openBlock(), _createElementBlock("input", {
+        "type": 1,
+        "onUpdate:modelValue": ($event)=>value = $event
+    }, null, 8, [
+        "onUpdate:modelValue"
+    ])), [
+        [
+            _vModelDynamic,
+            value
+        ]
+    ]);
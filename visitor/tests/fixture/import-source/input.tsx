@@ -0,0 +1,16 @@
+import { defineComponent } from '@vue/runtime-core'
+import { ref } from 'vue'
+
+interface Props {
+  msg: string
+}
+
+const Comp = defineComponent((props: Props) => {
+  const count = ref(0)
+  return () => (
+    <>
+      <p>{props.msg}</p>
+      <span>{count.value}</span>
+    </>
+  )
+})
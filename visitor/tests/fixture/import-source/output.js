@@ -0,0 +1,24 @@
+import { defineComponent, Fragment as _Fragment, createElementBlock as _createElementBlock, createVNode as _createVNode, openBlock as _openBlock } from '@vue/runtime-core';
+import { ref } from 'vue';
+interface Props {
+    msg: string;
+}
+const Comp = defineComponent((props: Props)=>{
+    const count = ref(0);
+    return ()=>(_openBlock(true), _createElementBlock(_Fragment, null, [
+            _createVNode("p", null, [
+                props.msg
+            ]),
+            _createVNode("span", null, [
+                count.value
+            ])
+        ], 256));
+}, {
+    props: {
+        msg: {
+            type: String,
+            required: true
+        }
+    },
+    name: "Comp"
+});
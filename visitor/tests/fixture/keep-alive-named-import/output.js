@@ -1,3 +1,4 @@
-import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
-import { KeepAlive } from 'vue';
-_createVNode(KeepAlive, null, [_createTextVNode("123")]);
+import { KeepAlive, createTextVNode as _createTextVNode, createVNode as _createVNode } from 'vue';
+_createVNode(KeepAlive, null, [
+    _createTextVNode("123")
+]);
@@ -1,3 +1,4 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
 import * as Vue from 'vue';
-_createVNode("div", null, [_createTextVNode("Vue")]);
+const _hoisted_1 = _createVNode("div", null, [_createTextVNode("Vue")], -1);
+_hoisted_1;
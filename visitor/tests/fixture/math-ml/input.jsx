@@ -0,0 +1,8 @@
+const Fraction = ({ numerator, denominator }) => (
+  <math display="block">
+    <mfrac>
+      <mi>{numerator}</mi>
+      <mn>{denominator}</mn>
+    </mfrac>
+  </math>
+)
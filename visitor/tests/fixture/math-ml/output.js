@@ -0,0 +1,13 @@
+import { createVNode as _createVNode } from "vue";
+const Fraction = ({ numerator, denominator })=>_createVNode("math", {
+        "display": "block"
+    }, [
+        _createVNode("mfrac", null, [
+            _createVNode("mi", null, [
+                numerator
+            ]),
+            _createVNode("mn", null, [
+                denominator
+            ])
+        ])
+    ]);
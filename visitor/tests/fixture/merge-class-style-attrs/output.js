@@ -1,5 +1,5 @@
-import { createVNode as _createVNode } from "vue";
-_createVNode("div", {
+import { createElementBlock as _createElementBlock, openBlock as _openBlock } from "vue";
+_openBlock(), _createElementBlock("div", {
   "class": ["a", b],
   "style": ["color: red", s]
 }, null, 6);
@@ -1,5 +1,5 @@
-import { createTextVNode as _createTextVNode, createVNode as _createVNode, mergeProps as _mergeProps } from "vue";
-_createVNode("button", _mergeProps({
+import { createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, mergeProps as _mergeProps, openBlock as _openBlock } from "vue";
+_openBlock(), _createElementBlock("button", _mergeProps({
   "loading": true
 }, x, {
   "type": "submit"
@@ -1,5 +1,9 @@
-import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("C"), {
-  "model": foo,
-  "onUpdate:model": $event => foo = $event
-}, null, 8, ["model", "onUpdate:model"]);
+import { createBlock as _createBlock, openBlock as _openBlock, resolveComponent as _resolveComponent } from "vue";
+const _component_C = _resolveComponent("C");
+_openBlock(), _createBlock(_component_C, {
+    "model": foo,
+    "onUpdate:model": ($event)=>foo = $event
+}, null, 8, [
+    "model",
+    "onUpdate:model"
+]);
@@ -1,5 +1,9 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("A"), null, {
-  default: () => [foo, bar],
-  _: 1
+const _component_A = _resolveComponent("A");
+_createVNode(_component_A, null, {
+    default: ()=>[
+            foo,
+            bar
+        ],
+    _: 1
 });
@@ -1,11 +1,12 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("A"), {
-  "loading": true,
-  ...a,
-  b: 1,
-  c: {
-    d: 2
-  },
-  "class": "x",
-  "style": x
+const _component_A = _resolveComponent("A");
+_createVNode(_component_A, {
+    "loading": true,
+    ...a,
+    b: 1,
+    c: {
+        d: 2
+    },
+    "class": "x",
+    "style": x
 }, null);
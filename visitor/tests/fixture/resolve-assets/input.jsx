@@ -0,0 +1,29 @@
+const Table = () => (
+  <table>
+    <my-row v-focus>
+      <MyCell v-focus />
+      <MyCell />
+    </my-row>
+  </table>
+)
+
+function render() {
+  const cells = items.map((item) => <MyCell>{item}</MyCell>)
+  return <MyRow>{cells}</MyRow>
+}
+
+const Header = () => {
+  const header = <MyHeader />
+  const onClick = () => count.value++
+  return <div onClick={onClick}>{header}</div>
+}
+
+const Body = () => {
+  const body = <MyBody />
+  if (cond) {
+    log()
+  }
+  return body
+}
+
+const List = () => <ul>{items.map((item) => <MyItem>{item}</MyItem>)}</ul>
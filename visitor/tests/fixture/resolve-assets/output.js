@@ -0,0 +1,70 @@
+import { createBlock as _createBlock, createElementBlock as _createElementBlock, createVNode as _createVNode, isVNode as _isVNode, openBlock as _openBlock, resolveComponent as _resolveComponent, resolveDirective as _resolveDirective, withDirectives as _withDirectives } from "vue";
+function _isSlot(s) {
+    return typeof s === "function" || ({}).toString.call(s) === "[object Object]" && !_isVNode(s);
+}
+const Table = ()=>{
+    const _component_my_row = _resolveComponent("my-row"), _component_MyCell = _resolveComponent("MyCell"), _directive_focus = _resolveDirective("focus");
+    return _openBlock(), _createElementBlock("table", null, [
+        _withDirectives(_createVNode(_component_my_row, null, {
+            default: ()=>[
+                    _withDirectives(_createVNode(_component_MyCell, null, null, 512), [
+                        [
+                            _directive_focus,
+                        ]
+                    ]),
+                    _createVNode(_component_MyCell, null, null)
+                ],
+            _: 1
+        }, 512), [
+            [
+                _directive_focus,
+            ]
+        ])
+    ]);
+};
+function render() {
+    const _component_MyCell = _resolveComponent("MyCell"), _component_MyRow = _resolveComponent("MyRow");
+    const cells = items.map((item)=>_createVNode(_component_MyCell, null, _isSlot(item) ? item : {
+            default: ()=>[
+                    item
+                ],
+            _: 2
+        }));
+    return _createVNode(_component_MyRow, null, _isSlot(cells) ? cells : {
+        default: ()=>[
+                cells
+            ],
+        _: 2
+    });
+}
+const Header = ()=>{
+    const _component_MyHeader = _resolveComponent("MyHeader");
+    const header = (_openBlock(), _createBlock(_component_MyHeader, null, null));
+    const onClick = ()=>count.value++;
+    return _createVNode("div", {
+        "onClick": onClick
+    }, [
+        header
+    ], 8, [
+        "onClick"
+    ]);
+};
+const Body = ()=>{
+    const _component_MyBody = _resolveComponent("MyBody");
+    const body = (_openBlock(), _createBlock(_component_MyBody, null, null));
+    if (cond) {
+        log();
+    }
+    return body;
+};
+const List = ()=>{
+    const _component_MyItem = _resolveComponent("MyItem");
+    return _createVNode("ul", null, [
+        items.map((item)=>_createVNode(_component_MyItem, null, _isSlot(item) ? item : {
+                default: ()=>[
+                        item
+                    ],
+                _: 2
+            }))
+    ]);
+};
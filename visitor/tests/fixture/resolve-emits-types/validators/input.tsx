@@ -0,0 +1,27 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+interface Item {
+  id: number
+}
+
+enum Direction {
+  Up,
+  Down,
+}
+
+interface Emits {
+  (e: 'change', value: string): void
+  (e: 'change', count: number): void
+  (e: 'select' | 'focus', item: Item, index?: number): void
+  (e: 'update:date', date: Date | null): void
+  (e: 'close'): void
+  (e: 'close', reason: string): void
+  toggle(checked: boolean, ...rest: unknown[]): void
+  'update:tags': (tags: string[]) => void
+  // types below can't be checked exactly, so they aren't validated
+  move(direction: Direction): void
+  sort(key: keyof Item, desc: boolean): void
+  submit(data: Object): void
+}
+
+defineComponent((props: { modelValue: number, 'onUpdate:modelValue': (value: number) => void }, ctx: SetupContext<Emits>) => {})
@@ -0,0 +1,54 @@
+import { defineComponent, type SetupContext } from 'vue';
+interface Item {
+    id: number;
+}
+enum Direction {
+    Up,
+    Down
+}
+interface Emits {
+    (e: 'change', value: string) : void;
+    (e: 'change', count: number) : void;
+    (e: 'select' | 'focus', item: Item, index?: number) : void;
+    (e: 'update:date', date: Date | null) : void;
+    (e: 'close') : void;
+    (e: 'close', reason: string) : void;
+    toggle(checked: boolean, ...rest: unknown[]): void;
+    'update:tags': (tags: string[]) => void;
+    // types below can't be checked exactly, so they aren't validated
+    move(direction: Direction): void;
+    sort(key: keyof Item, desc: boolean): void;
+    submit(data: Object): void;
+}
+defineComponent((props: {
+    modelValue: number;
+    'onUpdate:modelValue': (value: number) => void;
+}, ctx: SetupContext<Emits>)=>{}, {
+    props: {
+        modelValue: {
+            type: Number,
+            required: true
+        },
+        'onUpdate:modelValue': {
+            type: Function,
+            required: true
+        },
+        modelModifiers: {
+            type: Object,
+            required: false
+        }
+    },
+    emits: {
+        change: (value)=>typeof value === "string" || typeof value === "number",
+        select: (item, index)=>typeof item === "object" && item !== null && (typeof index === "number" || index === undefined),
+        focus: (item, index)=>typeof item === "object" && item !== null && (typeof index === "number" || index === undefined),
+        "update:date": (date)=>date instanceof Date || date === null,
+        close: null,
+        toggle: (checked)=>typeof checked === "boolean",
+        "update:tags": (tags)=>Array.isArray(tags),
+        move: null,
+        sort: null,
+        submit: null,
+        "update:modelValue": null
+    }
+});
@@ -0,0 +1,7 @@
+import { defineComponent } from 'vue'
+import type { Props } from './props'
+import { type Size as ButtonSize, type Variant } from './types'
+
+defineComponent((props: Props) => { })
+
+defineComponent((props: { size: ButtonSize; variant?: Variant }) => { })
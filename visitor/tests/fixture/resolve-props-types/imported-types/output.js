@@ -0,0 +1,41 @@
+import { defineComponent } from 'vue';
+import type { Props } from './props';
+import { type Size as ButtonSize, type Variant } from './types';
+defineComponent((props: Props)=>{}, {
+    props: {
+        label: {
+            type: String,
+            required: true
+        },
+        onClick: {
+            type: Function,
+            required: true
+        },
+        disabled: {
+            type: Boolean,
+            required: false
+        },
+        tabindex: {
+            type: [
+                Number,
+                String
+            ],
+            required: true
+        }
+    }
+});
+defineComponent((props: {
+    size: ButtonSize;
+    variant?: Variant;
+})=>{}, {
+    props: {
+        size: {
+            type: String,
+            required: true
+        },
+        variant: {
+            type: Object,
+            required: false
+        }
+    }
+});
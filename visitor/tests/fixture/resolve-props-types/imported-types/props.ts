@@ -0,0 +1,6 @@
+import type { BaseProps } from './types'
+
+export interface Props extends BaseProps {
+  label: string
+  onClick: () => void
+}
@@ -0,0 +1,6 @@
+interface BaseProps {
+  disabled?: boolean
+  tabindex: number | string
+}
+
+export { BaseProps }
@@ -0,0 +1,3 @@
+export type Size = 'small' | 'large'
+
+export type Variant = Record<string, string>
@@ -0,0 +1,18 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+interface Props {
+  modelValue: string
+  'onUpdate:modelValue'?: (value: string) => void
+  title?: string
+  titleModifiers?: { trim?: boolean }
+  count: number
+}
+
+interface Emits {
+  (e: 'update:title', value: string): void
+  (e: 'update:count', value: number): void
+}
+
+defineComponent((props: Props, ctx: SetupContext<Emits>) => {})
+
+defineComponent((props: { checked: boolean, 'onUpdate:checked': (value: boolean) => void }) => {})
@@ -0,0 +1,73 @@
+import { defineComponent, type SetupContext } from 'vue';
+interface Props {
+    modelValue: string;
+    'onUpdate:modelValue'?: (value: string) => void;
+    title?: string;
+    titleModifiers?: {
+        trim?: boolean;
+    };
+    count: number;
+}
+interface Emits {
+    (e: 'update:title', value: string) : void;
+    (e: 'update:count', value: number) : void;
+}
+defineComponent((props: Props, ctx: SetupContext<Emits>)=>{}, {
+    props: {
+        modelValue: {
+            type: String,
+            required: true
+        },
+        'onUpdate:modelValue': {
+            type: Function,
+            required: false
+        },
+        title: {
+            type: String,
+            required: false
+        },
+        titleModifiers: {
+            type: Object,
+            required: false
+        },
+        count: {
+            type: Number,
+            required: true
+        },
+        modelModifiers: {
+            type: Object,
+            required: false
+        },
+        countModifiers: {
+            type: Object,
+            required: false
+        }
+    },
+    emits: [
+        "update:title",
+        "update:count",
+        "update:modelValue"
+    ]
+});
+defineComponent((props: {
+    checked: boolean;
+    'onUpdate:checked': (value: boolean) => void;
+})=>{}, {
+    props: {
+        checked: {
+            type: Boolean,
+            required: true
+        },
+        'onUpdate:checked': {
+            type: Function,
+            required: true
+        },
+        checkedModifiers: {
+            type: Object,
+            required: false
+        }
+    },
+    emits: [
+        "update:checked"
+    ]
+});
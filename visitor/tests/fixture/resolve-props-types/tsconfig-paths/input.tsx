@@ -0,0 +1,5 @@
+import { defineComponent } from 'vue'
+import type { Item } from '@/shared/item.js'
+import type { ListProps } from '@shared'
+
+defineComponent((props: ListProps & { selected: Item }) => { })
@@ -0,0 +1,21 @@
+import { defineComponent } from 'vue';
+import type { Item } from '@/shared/item.js';
+import type { ListProps } from '@shared';
+defineComponent((props: ListProps & {
+    selected: Item;
+})=>{}, {
+    props: {
+        items: {
+            type: Array,
+            required: true
+        },
+        active: {
+            type: Number,
+            required: true
+        },
+        selected: {
+            type: Object,
+            required: true
+        }
+    }
+});
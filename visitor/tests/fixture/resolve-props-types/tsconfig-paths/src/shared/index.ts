@@ -0,0 +1,6 @@
+import type { Item } from './item'
+
+export type ListProps = {
+  items: Item[]
+  active: Item['id']
+}
@@ -0,0 +1,27 @@
+import { defineComponent } from 'vue'
+
+interface Props {
+  title: string
+  count?: number
+}
+
+interface Emits {
+  (e: 'change', value: string): void
+}
+
+defineComponent<Props, Emits>((props) => {})
+
+defineComponent<{ msg: string }>(function (props) {})
+
+defineComponent<Props>((props, { emit }) => {}, {
+  name: 'Counter',
+})
+
+defineComponent<Props>((props) => {}, {
+  props: ['title', 'count'],
+})
+
+// second argument is ignored with options object, so it shouldn't be resolved
+defineComponent<Props>({
+  setup(props) {},
+})
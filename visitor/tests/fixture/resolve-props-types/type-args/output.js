@@ -0,0 +1,56 @@
+import { defineComponent } from 'vue';
+interface Props {
+    title: string;
+    count?: number;
+}
+interface Emits {
+    (e: 'change', value: string) : void;
+}
+defineComponent<Props, Emits>((props)=>{}, {
+    props: {
+        title: {
+            type: String,
+            required: true
+        },
+        count: {
+            type: Number,
+            required: false
+        }
+    },
+    emits: [
+        "change"
+    ]
+});
+defineComponent<{
+    msg: string;
+}>(function(props) {}, {
+    props: {
+        msg: {
+            type: String,
+            required: true
+        }
+    }
+});
+defineComponent<Props>((props, { emit })=>{}, {
+    name: 'Counter',
+    props: {
+        title: {
+            type: String,
+            required: true
+        },
+        count: {
+            type: Number,
+            required: false
+        }
+    }
+});
+defineComponent<Props>((props)=>{}, {
+    props: [
+        'title',
+        'count'
+    ]
+});
+// second argument is ignored with options object, so it shouldn't be resolved
+defineComponent<Props>({
+    setup (props) {}
+});
@@ -0,0 +1,6 @@
+import { defineComponent } from 'vue'
+import type { RouteLocation } from 'vue-router'
+
+defineComponent((props: { title: string }) => {
+  const route: RouteLocation | undefined = undefined
+})
@@ -0,0 +1,14 @@
+import { defineComponent } from 'vue';
+import type { RouteLocation } from 'vue-router';
+defineComponent((props: {
+    title: string;
+})=>{
+    const route: RouteLocation | undefined = undefined;
+}, {
+    props: {
+        title: {
+            type: String,
+            required: true
+        }
+    }
+});
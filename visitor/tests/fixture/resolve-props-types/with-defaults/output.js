@@ -1,5 +1,4 @@
-import { mergeDefaults as _mergeDefaults } from "vue";
-import { defineComponent } from 'vue';
+import { defineComponent, mergeDefaults as _mergeDefaults } from 'vue';
 import { defaults } from './foo';
 defineComponent((props: {
     foo?: string;
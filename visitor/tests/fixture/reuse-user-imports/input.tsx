@@ -0,0 +1,8 @@
+import { createVNode as h, Fragment } from 'vue'
+import type { VNode } from 'vue'
+
+const Root = () => <Fragment><div>{h('p')}</div></Fragment>
+
+function render(h: VNode) {
+  return <span>{h}</span>
+}
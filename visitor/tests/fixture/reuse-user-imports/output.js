@@ -0,0 +1,12 @@
+import { createVNode as h, Fragment } from 'vue';
+import type { VNode } from 'vue';
+const Root = ()=>h(Fragment, null, [
+        h("div", null, [
+            h('p')
+        ])
+    ]);
+function render(h1: VNode) {
+    return h("span", null, [
+        h1
+    ]);
+}
@@ -0,0 +1,7 @@
+const App = () => (
+  <div>
+    <span>static</span>
+  </div>
+);
+
+const { createVNode } = Vue;
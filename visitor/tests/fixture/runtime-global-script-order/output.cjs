@@ -0,0 +1,8 @@
+const { createTextVNode: _createTextVNode } = Vue;
+const App = ()=>_hoisted_1;
+const { createVNode } = Vue;
+const _hoisted_1 = createVNode("div", null, [
+    createVNode("span", null, [
+        _createTextVNode("static")
+    ])
+], -1);
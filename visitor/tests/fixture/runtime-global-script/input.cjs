@@ -0,0 +1,3 @@
+const app = Vue.createApp({
+  render: () => <div onClick={() => alert("hello")}>{message}</div>,
+});
@@ -0,0 +1,8 @@
+const { createVNode: _createVNode } = window.Vue;
+const app = Vue.createApp({
+    render: ()=>_createVNode("div", {
+            "onClick": ()=>alert("hello")
+        }, [
+            message
+        ])
+});
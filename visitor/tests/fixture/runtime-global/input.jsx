@@ -0,0 +1,10 @@
+const { defineComponent, ref } = Vue
+
+const Static = () => <p class="static">text</p>
+
+const Comp = defineComponent(() => {
+  const visible = ref(true)
+  return () => <MyButton v-show={visible.value} v-once />
+})
+
+const { createTextVNode } = Vue
@@ -0,0 +1,21 @@
+const { createVNode: _createVNode, resolveComponent: _resolveComponent, setBlockTracking: _setBlockTracking, vShow: _vShow, withDirectives: _withDirectives } = Vue;
+const { defineComponent, ref } = Vue;
+const Static = ()=>_hoisted_1;
+const Comp = defineComponent(()=>{
+    const visible = ref(true);
+    return (_ctx, _cache)=>{
+        const _component_MyButton = _resolveComponent("MyButton");
+        return _cache[0] || (_setBlockTracking(-1), _cache[0] = _withDirectives(_createVNode(_component_MyButton, null, null, 512), [
+            [
+                _vShow,
+                visible.value
+            ]
+        ]), _setBlockTracking(1), _cache[0]);
+    };
+});
+const { createTextVNode } = Vue;
+const _hoisted_1 = _createVNode("p", {
+    "class": "static"
+}, [
+    createTextVNode("text")
+], -1);
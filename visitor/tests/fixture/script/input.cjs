@@ -0,0 +1,14 @@
+"use strict";
+const { defineComponent, ref } = require("vue");
+
+const Static = () => <p class="static">text</p>;
+
+const Counter = defineComponent(() => {
+  const count = ref(0);
+  return () => (
+    <div>
+      <button onClick={() => count.value++}>{count.value}</button>
+      <Child>{() => <span v-once>{count.value}</span>}</Child>
+    </div>
+  );
+});
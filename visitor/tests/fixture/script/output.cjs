@@ -0,0 +1,29 @@
+"use strict";
+const { createTextVNode: _createTextVNode, createVNode: _createVNode, resolveComponent: _resolveComponent, setBlockTracking: _setBlockTracking } = require("vue");
+const { defineComponent, ref } = require("vue");
+const _hoisted_1 = _createVNode("p", {
+    "class": "static"
+}, [
+    _createTextVNode("text")
+], -1);
+const Static = ()=>_hoisted_1;
+const Counter = defineComponent(()=>{
+    const count = ref(0);
+    return (_ctx, _cache)=>{
+        const _component_Child = _resolveComponent("Child");
+        return _createVNode("div", null, [
+            _createVNode("button", {
+                "onClick": ()=>count.value++
+            }, [
+                count.value
+            ], 8, [
+                "onClick"
+            ]),
+            _createVNode(_component_Child, null, {
+                default: ()=>_cache[0] || (_setBlockTracking(-1), _cache[0] = _createVNode("span", null, [
+                        count.value
+                    ]), _setBlockTracking(1), _cache[0])
+            })
+        ]);
+    };
+});
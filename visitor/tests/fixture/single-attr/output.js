@@ -1,2 +1,2 @@
-import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
-_createVNode("div", x, [_createTextVNode("single")], 16);
+import { createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, openBlock as _openBlock } from "vue";
+_openBlock(), _createElementBlock("div", x, [_createTextVNode("single")], 16);
@@ -3,10 +3,11 @@ function _isSlot(s) {
     return typeof s === "function" || ({}).toString.call(s) === "[object Object]" && !_isVNode(s);
 }
 const Component = (row)=>{
+    const _component_NSpace = _resolveComponent("NSpace"), _component_NButton = _resolveComponent("NButton");
     let _slot, _slot2, _slot3;
-    return _createVNode(_resolveComponent("NSpace"), null, {
+    return _createVNode(_component_NSpace, null, {
         default: ()=>[
-                _createVNode(_resolveComponent("NButton"), {
+                _createVNode(_component_NButton, {
                     "type": "primary",
                     "secondary": true,
                     "onClick": handler1
@@ -19,7 +20,7 @@ const Component = (row)=>{
                     "secondary",
                     "onClick"
                 ]),
-                _createVNode(_resolveComponent("NButton"), {
+                _createVNode(_component_NButton, {
                     "onClick": handler2
                 }, _isSlot(_slot2 = t('text2')) ? _slot2 : {
                     default: ()=>[
@@ -29,7 +30,7 @@ const Component = (row)=>{
                 }, 8, [
                     "onClick"
                 ]),
-                _createVNode(_resolveComponent("NButton"), {
+                _createVNode(_component_NButton, {
                     "type": "error",
                     "onClick": handler3
                 }, _isSlot(_slot3 = t('text3')) ? _slot3 : {
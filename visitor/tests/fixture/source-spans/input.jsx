@@ -0,0 +1,17 @@
+const App = () => (
+  /* root element */
+  <div id={id}>
+    <span
+      // dynamic class
+      class={cls}
+      v-show={visible}
+    >
+      text
+    </span>
+    {/* dropped */}
+    <Comp v-model={value}>
+      {/* children */}
+      {slot}
+    </Comp>
+  </div>
+);
@@ -0,0 +1,30 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, isVNode as _isVNode, resolveComponent as _resolveComponent, vShow as _vShow, withDirectives as _withDirectives } from "vue";
+function _isSlot(s) {
+    return typeof s === "function" || ({}).toString.call(s) === "[object Object]" && !_isVNode(s);
+}
+const App = ()=>{
+    const _component_Comp = _resolveComponent("Comp");
+    return /* root element */ _createVNode("div", {
+        "id": id
+    }, [
+        _withDirectives(_createVNode("span", {
+            // dynamic class
+            "class": cls
+        }, [
+            _createTextVNode("text")
+        ]), [
+            [
+                _vShow,
+                visible
+            ]
+        ]),
+        _createVNode(_component_Comp, {
+            "modelValue": value,
+            "onUpdate:modelValue": ($event)=>value = $event
+        }, _isSlot(slot) ? slot : {
+            default: ()=>[
+                    slot
+                ]
+        })
+    ]);
+};
@@ -1,4 +1,3 @@
-import { createVNode as _createVNode } from "vue";
-import { createVNode, Fragment as _Fragment } from 'vue';
+import { createVNode, Fragment as _Fragment, createBlock as _createBlock, openBlock as _openBlock } from 'vue';
 import { vShow } from 'vue';
-_createVNode(_Fragment, null, null);
+_openBlock(), _createBlock(_Fragment, null, null);
@@ -0,0 +1,60 @@
+import { defineComponent, Teleport } from 'vue'
+import Child from './Child'
+
+defineComponent((props, { slots }) => {
+  return () => (
+    <div class="wrapper" id={props.id}>
+      <h1>Hello & {`${props.name}!`}</h1>
+      <img src={props.src} />
+      <Child title={props.title}>
+        <span>{props.count + 1}</span>
+      </Child>
+      {slots.default()}
+      {props.content}
+      <p v-show={props.visible}>shown</p>
+      <Teleport to="body">
+        <span>teleported</span>
+      </Teleport>
+    </div>
+  )
+})
+
+defineComponent({
+  setup() {
+    const count = 1
+    return () => <Child count={count} />
+  },
+})
+
+defineComponent(() => () => (
+  <>
+    <span>a</span>
+    {slots.footer?.()}
+  </>
+))
+
+defineComponent(() => () => (props.ok ? <div /> : <span />))
+
+defineComponent({
+  setup() {
+    return () => (
+      <div>
+        <MyComp />
+      </div>
+    )
+  },
+})
+
+defineComponent((props, ctx) => () => <div>{ctx.slots.header()}</div>)
+
+defineComponent({
+  setup(props, { slots: s }) {
+    const slots = { default: () => 'local' }
+    return () => (
+      <div>
+        {s.default({ msg: 'hi' })}
+        {slots.default()}
+      </div>
+    )
+  },
+})
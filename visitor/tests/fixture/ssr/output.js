@@ -0,0 +1,95 @@
+import { ssrInterpolate as _ssrInterpolate, ssrRenderAttrs as _ssrRenderAttrs, ssrRenderComponent as _ssrRenderComponent, ssrRenderSlot as _ssrRenderSlot, ssrRenderVNode as _ssrRenderVNode } from "@vue/server-renderer";
+import { defineComponent, Teleport, createTextVNode as _createTextVNode, createVNode as _createVNode, mergeProps as _mergeProps, resolveComponent as _resolveComponent, ssrUtils as _ssrUtils, vShow as _vShow, withDirectives as _withDirectives } from 'vue';
+import Child from './Child';
+defineComponent((props1, { slots: slots1 })=>{
+    return (_ctx, _push, _parent, _attrs)=>{
+        _push(`<div${_ssrRenderAttrs(_mergeProps({
+            "class": "wrapper",
+            "id": props1.id
+        }, _attrs), "div")}><h1>Hello &amp;${_ssrInterpolate(`${props1.name}!`)}</h1><img${_ssrRenderAttrs({
+            "src": props1.src
+        }, "img")}>`);
+        _push(_ssrRenderComponent(Child, {
+            "title": props1.title
+        }, {
+            default: ()=>[
+                    _createVNode("span", null, [
+                        props1.count + 1
+                    ])
+                ]
+        }, _parent));
+        _ssrRenderSlot(slots1, "default", {}, null, _push, _parent);
+        _ssrRenderVNode(_push, _ssrUtils.normalizeVNode(props1.content), _parent);
+        _ssrRenderVNode(_push, _withDirectives(_createVNode("p", null, [
+            _createTextVNode("shown")
+        ]), [
+            [
+                _vShow,
+                props1.visible
+            ]
+        ]), _parent);
+        _ssrRenderVNode(_push, _createVNode(Teleport, {
+            "to": "body"
+        }, [
+            _createVNode("span", null, [
+                _createTextVNode("teleported")
+            ])
+        ]), _parent);
+        _push(`</div>`);
+    };
+}, {
+    __ssrInlineRender: true
+});
+defineComponent({
+    setup () {
+        const count = 1;
+        return (_ctx, _push, _parent, _attrs)=>{
+            _push(_ssrRenderComponent(Child, _mergeProps({
+                "count": count
+            }, _attrs), null, _parent));
+        };
+    },
+    __ssrInlineRender: true
+});
+defineComponent(()=>(_ctx, _push, _parent, _attrs)=>{
+        _push(`<!--[--><span>a</span>`);
+        _ssrRenderVNode(_push, _ssrUtils.normalizeVNode(slots.footer?.()), _parent);
+        _push(`<!--]-->`);
+    }, {
+    __ssrInlineRender: true
+});
+defineComponent(()=>()=>props.ok ? _createVNode("div", null, null) : _createVNode("span", null, null));
+defineComponent({
+    setup () {
+        return (_ctx, _push, _parent, _attrs)=>{
+            const _component_MyComp = _resolveComponent("MyComp");
+            _push(`<div${_ssrRenderAttrs(_attrs, "div")}>`);
+            _push(_ssrRenderComponent(_component_MyComp, null, null, _parent));
+            _push(`</div>`);
+        };
+    },
+    __ssrInlineRender: true
+});
+defineComponent((props1, ctx)=>(_ctx, _push, _parent, _attrs)=>{
+        _push(`<div${_ssrRenderAttrs(_attrs, "div")}>`);
+        _ssrRenderSlot(ctx.slots, "header", {}, null, _push, _parent);
+        _push(`</div>`);
+    }, {
+    __ssrInlineRender: true
+});
+defineComponent({
+    setup (props1, { slots: s }) {
+        const slots1 = {
+            default: ()=>'local'
+        };
+        return (_ctx, _push, _parent, _attrs)=>{
+            _push(`<div${_ssrRenderAttrs(_attrs, "div")}>`);
+            _ssrRenderSlot(s, "default", {
+                msg: 'hi'
+            }, null, _push, _parent);
+            _ssrRenderVNode(_push, _ssrUtils.normalizeVNode(slots1.default()), _parent);
+            _push(`</div>`);
+        };
+    },
+    __ssrInlineRender: true
+});
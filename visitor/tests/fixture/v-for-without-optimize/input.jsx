@@ -0,0 +1,7 @@
+const List = () => (
+  <ul>
+    <li v-for={item in items} key={item.id}>
+      {item.name}
+    </li>
+  </ul>
+);
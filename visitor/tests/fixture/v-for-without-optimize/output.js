@@ -0,0 +1,8 @@
+import { Fragment as _Fragment, createVNode as _createVNode, renderList as _renderList } from "vue";
+const List = ()=>_createVNode("ul", null, [
+        _createVNode(_Fragment, null, _renderList(items, (item)=>_createVNode("li", {
+                "key": item.id
+            }, [
+                item.name
+            ])), 128)
+    ]);
@@ -0,0 +1,16 @@
+const List = () => (
+  <ul>
+    <li v-for={item in items} key={item.id}>
+      {item.name}
+    </li>
+  </ul>
+);
+
+const Indexed = () => (
+  <div>
+    <span v-for={(item, index) in items}>{index}: {item}</span>
+    <Item v-for={[item, index] in items} key={index} value={item} />
+  </div>
+);
+
+const Root = () => <p v-for={n in 3} class="static">text</p>;
@@ -0,0 +1,30 @@
+import { Fragment as _Fragment, createBlock as _createBlock, createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, createVNode as _createVNode, openBlock as _openBlock, renderList as _renderList, resolveComponent as _resolveComponent } from "vue";
+const _hoisted_1 = _createVNode("p", {
+    "class": "static"
+}, [
+    _createTextVNode("text")
+], -1);
+const List = ()=>(_openBlock(), _createElementBlock("ul", null, [
+        (_openBlock(true), _createElementBlock(_Fragment, null, _renderList(items, (item)=>_createVNode("li", {
+                "key": item.id
+            }, [
+                item.name
+            ])), 128))
+    ]));
+const Indexed = ()=>{
+    const _component_Item = _resolveComponent("Item");
+    return _openBlock(), _createElementBlock("div", null, [
+        (_openBlock(true), _createElementBlock(_Fragment, null, _renderList(items, (item, index)=>_createVNode("span", null, [
+                index,
+                _createTextVNode(":"),
+                item
+            ])), 256)),
+        (_openBlock(true), _createElementBlock(_Fragment, null, _renderList(items, (item, index)=>(_openBlock(), _createBlock(_component_Item, {
+                "key": index,
+                "value": item
+            }, null, 8, [
+                "value"
+            ]))), 128))
+    ]);
+};
+const Root = ()=>(_openBlock(true), _createElementBlock(_Fragment, null, _renderList(3, (n)=>_hoisted_1), 256));
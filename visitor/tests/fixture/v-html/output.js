@@ -1,4 +1,4 @@
-import { createVNode as _createVNode } from "vue";
-_createVNode("h1", {
+import { createElementBlock as _createElementBlock, openBlock as _openBlock } from "vue";
+_openBlock(), _createElementBlock("h1", {
   "innerHTML": "<div>foo</div>"
 }, null, 8, ["innerHTML"]);
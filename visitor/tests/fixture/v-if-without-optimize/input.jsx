@@ -0,0 +1,6 @@
+const App = () => (
+  <Comp>
+    <p v-if={a}>a</p>
+    <p v-else>b</p>
+  </Comp>
+);
@@ -0,0 +1,17 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
+const App = ()=>{
+    const _component_Comp = _resolveComponent("Comp");
+    return _createVNode(_component_Comp, null, {
+        default: ()=>[
+                a ? _createVNode("p", {
+                    "key": 0
+                }, [
+                    _createTextVNode("a")
+                ]) : _createVNode("p", {
+                    "key": 1
+                }, [
+                    _createTextVNode("b")
+                ])
+            ]
+    });
+};
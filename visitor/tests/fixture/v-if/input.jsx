@@ -0,0 +1,23 @@
+import { defineComponent } from 'vue'
+
+const App = () => (
+  <div>
+    <p v-if={a}>a</p>
+    {/* comment */}
+    <p v-else-if={b} class={cls}>b</p>
+    <span v-else>c</span>
+    <Comp v-if={show} key="comp" />
+    <li v-if={list} v-for={item in items}>{item}</li>
+    <li v-if={loading}>loading</li>
+    <li v-else v-for={item in items} key={item.id}>{item.name}</li>
+  </div>
+);
+
+const Root = () => <div v-if={ok}>{text}</div>;
+
+const Cached = defineComponent(() => () => (
+  <div>
+    <i v-if={ok} v-once v-for={x in list}>{x}</i>
+    <b v-else>empty</b>
+  </div>
+));
@@ -0,0 +1,55 @@
+import { defineComponent, Fragment as _Fragment, createBlock as _createBlock, createCommentVNode as _createCommentVNode, createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, createVNode as _createVNode, openBlock as _openBlock, renderList as _renderList, resolveComponent as _resolveComponent, setBlockTracking as _setBlockTracking } from 'vue';
+const App = ()=>{
+    const _component_Comp = _resolveComponent("Comp");
+    return _openBlock(), _createElementBlock("div", null, [
+        a ? (_openBlock(), _createElementBlock("p", {
+            "key": 0
+        }, [
+            _createTextVNode("a")
+        ])) : b ? (_openBlock(), _createElementBlock("p", {
+            "key": 1,
+            "class": cls
+        }, [
+            _createTextVNode("b")
+        ], 2)) : (_openBlock(), _createElementBlock("span", {
+            "key": 2
+        }, [
+            _createTextVNode("c")
+        ])),
+        show ? (_openBlock(), _createBlock(_component_Comp, {
+            "key": "comp"
+        }, null)) : _createCommentVNode("v-if", true),
+        list ? (_openBlock(true), _createElementBlock(_Fragment, {
+            "key": 4
+        }, _renderList(items, (item)=>_createVNode("li", null, [
+                item
+            ])), 256)) : _createCommentVNode("v-if", true),
+        loading ? (_openBlock(), _createElementBlock("li", {
+            "key": 5
+        }, [
+            _createTextVNode("loading")
+        ])) : (_openBlock(true), _createElementBlock(_Fragment, {
+            "key": 6
+        }, _renderList(items, (item)=>_createVNode("li", {
+                "key": item.id
+            }, [
+                item.name
+            ])), 128))
+    ]);
+};
+const Root = ()=>ok ? _createVNode("div", {
+        "key": 0
+    }, [
+        text
+    ]) : _createCommentVNode("v-if", true);
+const Cached = defineComponent(()=>(_ctx, _cache)=>_createVNode("div", null, [
+            ok ? _cache[0] || (_setBlockTracking(-1), _cache[0] = (_openBlock(true), _createElementBlock(_Fragment, {
+                "key": 0
+            }, _renderList(list, (x)=>_createVNode("i", null, [
+                    x
+                ])), 256)), _setBlockTracking(1), _cache[0]) : (_openBlock(), _createElementBlock("b", {
+                "key": 1
+            }, [
+                _createTextVNode("empty")
+            ]))
+        ]));
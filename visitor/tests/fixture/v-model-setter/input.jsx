@@ -0,0 +1,8 @@
+const Form = () => (
+  <form>
+    <input v-model={store.forms[form.id].fields[field].value} />
+    <input v-model={[() => props.list[i]?.name, (name) => updateName(i, name)]} />
+    <input v-model={[() => state.text, (text) => (state.text = text), ['trim']]} />
+    <Editor v-model={[() => doc.content, (value) => doc.setContent(value), 'content']} />
+  </form>
+)
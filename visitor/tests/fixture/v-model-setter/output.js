@@ -0,0 +1,47 @@
+import { createElementBlock as _createElementBlock, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent, vModelText as _vModelText, withDirectives as _withDirectives } from "vue";
+const Form = ()=>{
+    const _component_Editor = _resolveComponent("Editor");
+    return _openBlock(), _createElementBlock("form", null, [
+        _withDirectives(_createVNode("input", {
+            "onUpdate:modelValue": ($event)=>store.forms[form.id].fields[field].value = $event
+        }, null, 8, [
+            "onUpdate:modelValue"
+        ]), [
+            [
+                _vModelText,
+                store.forms[form.id].fields[field].value
+            ]
+        ]),
+        _withDirectives(_createVNode("input", {
+            "onUpdate:modelValue": (name)=>updateName(i, name)
+        }, null, 8, [
+            "onUpdate:modelValue"
+        ]), [
+            [
+                _vModelText,
+                props.list[i]?.name
+            ]
+        ]),
+        _withDirectives(_createVNode("input", {
+            "onUpdate:modelValue": (text)=>state.text = text
+        }, null, 8, [
+            "onUpdate:modelValue"
+        ]), [
+            [
+                _vModelText,
+                state.text,
+                void 0,
+                {
+                    trim: true
+                }
+            ]
+        ]),
+        _createVNode(_component_Editor, {
+            "content": doc.content,
+            "onUpdate:content": (value)=>doc.setContent(value)
+        }, null, 8, [
+            "content",
+            "onUpdate:content"
+        ])
+    ]);
+};
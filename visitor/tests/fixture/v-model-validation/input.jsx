@@ -0,0 +1,9 @@
+const App = () => (
+  <div>
+    <div contenteditable v-model={state.html} />
+    <div contenteditable="false" v-model={state.text} />
+    <input v-model_trim_lazy={state.name} />
+    <input v-model={"text"} />
+    <Comp v-model_trimm={state.name} />
+  </div>
+)
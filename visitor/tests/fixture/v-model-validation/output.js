@@ -0,0 +1,61 @@
+import { createElementBlock as _createElementBlock, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent, resolveDirective as _resolveDirective, vModelText as _vModelText, withDirectives as _withDirectives } from "vue";
+const App = ()=>{
+    const _directive_model_editable = _resolveDirective("model-editable"), _component_Comp = _resolveComponent("Comp");
+    return _openBlock(), _createElementBlock("div", null, [
+        _withDirectives(_createVNode("div", {
+            "contenteditable": true,
+            "onUpdate:modelValue": ($event)=>state.html = $event
+        }, null, 8, [
+            "contenteditable",
+            "onUpdate:modelValue"
+        ]), [
+            [
+                _directive_model_editable,
+                state.html
+            ]
+        ]),
+        _withDirectives(_createVNode("div", {
+            "contenteditable": "false",
+            "onUpdate:modelValue": ($event)=>state.text = $event
+        }, null, 8, [
+            "onUpdate:modelValue"
+        ]), [
+            [
+                _vModelText,
+                state.text
+            ]
+        ]),
+        _withDirectives(_createVNode("input", {
+            "onUpdate:modelValue": ($event)=>state.name = $event
+        }, null, 8, [
+            "onUpdate:modelValue"
+        ]), [
+            [
+                _vModelText,
+                state.name,
+                void 0,
+                {
+                    lazy: true,
+                    trim: true
+                }
+            ]
+        ]),
+        _withDirectives(_createVNode("input", {
+            "onUpdate:modelValue": ($event)=>{}
+        }, null, 8, [
+            "onUpdate:modelValue"
+        ]), [
+            [
+                _vModelText,
+                "text"
+            ]
+        ]),
+        _createVNode(_component_Comp, {
+            "trimm": state.name,
+            "onUpdate:trimm": ($event)=>state.name = $event
+        }, null, 8, [
+            "trimm",
+            "onUpdate:trimm"
+        ])
+    ]);
+};
@@ -1,42 +1,51 @@
-import { Fragment as _Fragment, createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
+import { Fragment as _Fragment, createElementBlock as _createElementBlock, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent } from "vue";
+const _component_A = _resolveComponent("A"), _component_B = _resolveComponent("B"), _component_C = _resolveComponent("C"), _component_D = _resolveComponent("D"), _component_E = _resolveComponent("E"), _component_F = _resolveComponent("F");
 const foo = 'foo';
-const a = () => 'a';
+const a = ()=>'a';
 const b = {
-  c: 'c'
+    c: 'c'
 };
-_createVNode(_Fragment, null, [
-  _createVNode(_resolveComponent("A"), { [foo]: xx, ["onUpdate" + foo]: $event => xx = $event }, null, 16),
-  _createVNode(_resolveComponent("B"), {
-    "modelValue": xx,
-    "modelModifiers": { "a": true },
-    "onUpdate:modelValue": $event => xx = $event,
-  }, null, 8, ["modelValue", "onUpdate:modelValue"]),
-  _createVNode(_resolveComponent("C"), {
-    [foo]: xx,
-    [foo + "Modifiers"]: {
-      "a": true
-    },
-    ["onUpdate" + foo]: $event => xx = $event,
-  }, null, 16),
-  _createVNode(_resolveComponent("D"), {
-    [foo === 'foo' ? 'a' : 'b']: xx,
-    [(foo === 'foo' ? 'a' : 'b') + "Modifiers"]: {
-      "a": true
-    },
-    ["onUpdate" + (foo === 'foo' ? 'a' : 'b')]: $event => xx = $event,
-  }, null, 16),
-  _createVNode(_resolveComponent("E"), {
-    [a()]: xx,
-    [a() + "Modifiers"]: {
-      "a": true
-    },
-    ["onUpdate" + a()]: $event => xx = $event
-  }, null, 16),
-  _createVNode(_resolveComponent("F"), {
-    [b.c]: xx,
-    [b.c + "Modifiers"]: {
-      "a": true
-    },
-    ["onUpdate" + b.c]: $event => xx = $event
-  }, null, 16),
-]);
+_openBlock(true), _createElementBlock(_Fragment, null, [
+    _createVNode(_component_A, {
+        [foo]: xx,
+        ["onUpdate" + foo]: ($event)=>xx = $event
+    }, null, 16),
+    _createVNode(_component_B, {
+        "modelValue": xx,
+        "modelModifiers": {
+            "a": true
+        },
+        "onUpdate:modelValue": ($event)=>xx = $event
+    }, null, 8, [
+        "modelValue",
+        "onUpdate:modelValue"
+    ]),
+    _createVNode(_component_C, {
+        [foo]: xx,
+        [foo + "Modifiers"]: {
+            "a": true
+        },
+        ["onUpdate" + foo]: ($event)=>xx = $event
+    }, null, 16),
+    _createVNode(_component_D, {
+        [foo === 'foo' ? 'a' : 'b']: xx,
+        [(foo === 'foo' ? 'a' : 'b') + "Modifiers"]: {
+            "a": true
+        },
+        ["onUpdate" + (foo === 'foo' ? 'a' : 'b')]: ($event)=>xx = $event
+    }, null, 16),
+    _createVNode(_component_E, {
+        [a()]: xx,
+        [a() + "Modifiers"]: {
+            "a": true
+        },
+        ["onUpdate" + a()]: ($event)=>xx = $event
+    }, null, 16),
+    _createVNode(_component_F, {
+        [b.c]: xx,
+        [b.c + "Modifiers"]: {
+            "a": true
+        },
+        ["onUpdate" + b.c]: ($event)=>xx = $event
+    }, null, 16)
+], 256);
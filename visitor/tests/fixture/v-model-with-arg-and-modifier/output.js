@@ -1,5 +1,6 @@
-import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("Child"), {
+import { createBlock as _createBlock, openBlock as _openBlock, resolveComponent as _resolveComponent } from "vue";
+const _component_Child = _resolveComponent("Child");
+_openBlock(), _createBlock(_component_Child, {
     "value": this.foo,
     "valueModifiers": {
         "double": true
@@ -1,5 +1,5 @@
-import { createVNode as _createVNode,  vModelCheckbox as _vModelCheckbox, withDirectives as _withDirectives } from "vue";
-_withDirectives(_createVNode("input", {
+import { createElementBlock as _createElementBlock, openBlock as _openBlock, vModelCheckbox as _vModelCheckbox, withDirectives as _withDirectives } from "vue";
+_withDirectives((_openBlock(), _createElementBlock("input", {
     "type": "checkbox",
     "onUpdate:modelValue": $event => test = $event
-}, null, 8, ["onUpdate:modelValue"]), [[_vModelCheckbox, test]]);
+}, null, 8, ["onUpdate:modelValue"])), [[_vModelCheckbox, test]]);
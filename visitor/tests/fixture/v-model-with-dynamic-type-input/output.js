@@ -1,5 +1,5 @@
-import { createVNode as _createVNode, vModelDynamic as _vModelDynamic, withDirectives as _withDirectives } from "vue";
-_withDirectives(_createVNode("input", {
+import { createElementBlock as _createElementBlock, openBlock as _openBlock, vModelDynamic as _vModelDynamic, withDirectives as _withDirectives } from "vue";
+_withDirectives((_openBlock(), _createElementBlock("input", {
   "type": type,
   "onUpdate:modelValue": $event => test = $event
-}, null, 8, ["type", "onUpdate:modelValue"]), [[_vModelDynamic, test]]);
+}, null, 8, ["type", "onUpdate:modelValue"])), [[_vModelDynamic, test]]);
@@ -1,5 +1,5 @@
-import { Fragment as _Fragment, createVNode as _createVNode, vModelRadio as _vModelRadio, withDirectives as _withDirectives } from "vue";
-_createVNode(_Fragment, null, [_withDirectives(_createVNode("input", {
+import { Fragment as _Fragment, createElementBlock as _createElementBlock, createVNode as _createVNode, openBlock as _openBlock, vModelRadio as _vModelRadio, withDirectives as _withDirectives } from "vue";
+_openBlock(), _createElementBlock(_Fragment, null, [_withDirectives(_createVNode("input", {
     "type": "radio",
     "value": "1",
     "onUpdate:modelValue": $event => test = $event,
@@ -9,4 +9,4 @@ _createVNode(_Fragment, null, [_withDirectives(_createVNode("input", {
     "value": "2",
     "onUpdate:modelValue": $event => test = $event,
     "name": "test"
-}, null, 8, ["onUpdate:modelValue"]), [[_vModelRadio, test]])]);
+}, null, 8, ["onUpdate:modelValue"]), [[_vModelRadio, test]])], 64);
@@ -1,15 +1,18 @@
 import {
+  createElementBlock as _createElementBlock,
   createTextVNode as _createTextVNode,
   createVNode as _createVNode,
+  openBlock as _openBlock,
   vModelSelect as _vModelSelect,
   withDirectives as _withDirectives,
 } from "vue";
-_withDirectives(_createVNode("select", {
-  "onUpdate:modelValue": $event => test = $event
-}, [_createVNode("option", {
+const _hoisted_1 = _createVNode("option", {
   "value": "1"
-}, [_createTextVNode("a")]), _createVNode("option", {
+}, [_createTextVNode("a")], -1), _hoisted_2 = _createVNode("option", {
   "value": 2
-}, [_createTextVNode("b")]), _createVNode("option", {
+}, [_createTextVNode("b")], -1), _hoisted_3 = _createVNode("option", {
   "value": 3
-}, [_createTextVNode("c")])], 8, ["onUpdate:modelValue"]), [[_vModelSelect, test]]);
+}, [_createTextVNode("c")], -1);
+_withDirectives((_openBlock(), _createElementBlock("select", {
+  "onUpdate:modelValue": $event => test = $event
+}, [_hoisted_1, _hoisted_2, _hoisted_3], 8, ["onUpdate:modelValue"])), [[_vModelSelect, test]]);
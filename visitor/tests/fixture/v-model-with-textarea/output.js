@@ -1,4 +1,4 @@
-import { createVNode as _createVNode, vModelText as _vModelText, withDirectives as _withDirectives } from "vue";
-_withDirectives(_createVNode("textarea", {
+import { createElementBlock as _createElementBlock, openBlock as _openBlock, vModelText as _vModelText, withDirectives as _withDirectives } from "vue";
+_withDirectives((_openBlock(), _createElementBlock("textarea", {
   "onUpdate:modelValue": $event => test = $event
-}, null, 8, ["onUpdate:modelValue"]), [[_vModelText, test]]);
+}, null, 8, ["onUpdate:modelValue"])), [[_vModelText, test]]);
@@ -0,0 +1,30 @@
+import { defineComponent } from 'vue'
+
+export const Once = defineComponent(() => () => (
+  <div>
+    <span v-once>{count.value}</span>
+    <p v-if={ok.value} v-once>{msg.value}</p>
+    <ul v-once>
+      <li v-for={item in items}>{item}</li>
+    </ul>
+  </div>
+))
+
+export const Memo = defineComponent({
+  setup() {
+    return () => (
+      <div>
+        <span v-memo={[a.value]}>{a.value}</span>
+        <section v-memo={[show.value]}>{show.value && <Item />}</section>
+        <p v-memo={[c.value]} class={c.value}>text</p>
+        <Item v-memo={[b.value]} value={b.value} />
+        <li v-for={(item, index) in items} key={item.id} v-memo={[item.id === selected.value]}>
+          {index}: {item.name}
+        </li>
+        <li v-for={item in items} v-memo={[item]}>{item}</li>
+      </div>
+    )
+  },
+})
+
+export const WithoutCache = defineComponent(() => () => <div>{count.value}</div>)
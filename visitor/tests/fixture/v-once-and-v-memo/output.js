@@ -0,0 +1,78 @@
+import { defineComponent, Fragment as _Fragment, createBlock as _createBlock, createCommentVNode as _createCommentVNode, createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, createVNode as _createVNode, isMemoSame as _isMemoSame, openBlock as _openBlock, renderList as _renderList, resolveComponent as _resolveComponent, setBlockTracking as _setBlockTracking, withMemo as _withMemo } from 'vue';
+export const Once = defineComponent(()=>(_ctx, _cache)=>_createVNode("div", null, [
+            _cache[0] || (_setBlockTracking(-1), _cache[0] = _createVNode("span", null, [
+                count.value
+            ]), _setBlockTracking(1), _cache[0]),
+            ok.value ? _cache[1] || (_setBlockTracking(-1), _cache[1] = _createVNode("p", {
+                "key": 0
+            }, [
+                msg.value
+            ]), _setBlockTracking(1), _cache[1]) : _createCommentVNode("v-if", true),
+            _cache[2] || (_setBlockTracking(-1), _cache[2] = _createVNode("ul", null, [
+                (_openBlock(true), _createElementBlock(_Fragment, null, _renderList(items, (item)=>_createVNode("li", null, [
+                        item
+                    ])), 256))
+            ]), _setBlockTracking(1), _cache[2])
+        ]));
+export const Memo = defineComponent({
+    setup () {
+        return (_ctx, _cache)=>{
+            const _component_Item = _resolveComponent("Item");
+            return _createVNode("div", null, [
+                _withMemo([
+                    a.value
+                ], ()=>_createVNode("span", null, [
+                        a.value
+                    ]), _cache, 0),
+                _withMemo([
+                    show.value
+                ], ()=>_createVNode("section", null, [
+                        show.value && (_openBlock(), _createBlock(_component_Item, null, null))
+                    ]), _cache, 1),
+                _withMemo([
+                    c.value
+                ], ()=>(_openBlock(), _createElementBlock("p", {
+                        "class": c.value
+                    }, [
+                        _createTextVNode("text")
+                    ], 2)), _cache, 2),
+                _withMemo([
+                    b.value
+                ], ()=>(_openBlock(), _createBlock(_component_Item, {
+                        "value": b.value
+                    }, null, 8, [
+                        "value"
+                    ])), _cache, 3),
+                (_openBlock(true), _createElementBlock(_Fragment, null, _renderList(items, (item, index, ___, _cached)=>{
+                    const _memo = [
+                        item.id === selected.value
+                    ];
+                    if (_cached && _cached.key === item.id && _isMemoSame(_cached, _memo)) return _cached;
+                    const _item = _createVNode("li", {
+                        "key": item.id
+                    }, [
+                        index,
+                        _createTextVNode(":"),
+                        item.name
+                    ]);
+                    _item.memo = _memo;
+                    return _item;
+                }, _cache, 4), 128)),
+                (_openBlock(true), _createElementBlock(_Fragment, null, _renderList(items, (item, __, ___, _cached)=>{
+                    const _memo = [
+                        item
+                    ];
+                    if (_cached && _isMemoSame(_cached, _memo)) return _cached;
+                    const _item = _createVNode("li", null, [
+                        item
+                    ]);
+                    _item.memo = _memo;
+                    return _item;
+                }, _cache, 5), 256))
+            ]);
+        };
+    }
+});
+export const WithoutCache = defineComponent(()=>()=>_createVNode("div", null, [
+            count.value
+        ]));
@@ -0,0 +1 @@
+const Foo = () => <div v-once>{count.value}</div>
@@ -0,0 +1,4 @@
+import { createVNode as _createVNode } from "vue";
+const Foo = ()=>_createVNode("div", null, [
+        count.value
+    ]);
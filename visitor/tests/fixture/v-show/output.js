@@ -1,7 +1,8 @@
 import {
+  createElementBlock as _createElementBlock,
   createTextVNode as _createTextVNode,
-  createVNode as _createVNode,
+  openBlock as _openBlock,
   vShow as _vShow,
   withDirectives as _withDirectives,
 } from "vue";
-_withDirectives(_createVNode("div", null, [_createTextVNode("vShow")], 512), [[_vShow, x]]);
+_withDirectives((_openBlock(), _createElementBlock("div", null, [_createTextVNode("vShow")], 512)), [[_vShow, x]]);
@@ -1,17 +1,18 @@
-import {
-  createTextVNode as _createTextVNode,
-  createVNode as _createVNode,
-  resolveComponent as _resolveComponent,
-} from "vue";
-_createVNode("div", null, [
-  _createVNode(_resolveComponent("Comp"), null, {
-    default: () => [_createTextVNode("content")],
-    ...slots,
-    _: 1,
-  }),
-  _createVNode(_resolveComponent("Comp"), null, {
-    default: () => [_createTextVNode("content")],
-    a: b,
-    _: 1,
-  }),
+import { createElementBlock as _createElementBlock, createTextVNode as _createTextVNode, createVNode as _createVNode, openBlock as _openBlock, resolveComponent as _resolveComponent } from "vue";
+const _component_Comp = _resolveComponent("Comp");
+_openBlock(), _createElementBlock("div", null, [
+    _createVNode(_component_Comp, null, {
+        default: ()=>[
+                _createTextVNode("content")
+            ],
+        ...slots,
+        _: 1
+    }),
+    _createVNode(_component_Comp, null, {
+        default: ()=>[
+                _createTextVNode("content")
+            ],
+        a: b,
+        _: 1
+    })
 ]);
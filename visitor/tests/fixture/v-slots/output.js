@@ -1,2 +1,3 @@
-import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("A"), null, slots);
+import { createBlock as _createBlock, openBlock as _openBlock, resolveComponent as _resolveComponent } from "vue";
+const _component_A = _resolveComponent("A");
+_openBlock(), _createBlock(_component_A, null, slots);
@@ -1,4 +1,4 @@
-import { createVNode as _createVNode } from "vue";
-_createVNode("div", {
+import { createElementBlock as _createElementBlock, openBlock as _openBlock } from "vue";
+_openBlock(), _createElementBlock("div", {
   "textContent": text
 }, null, 8, ["textContent"]);
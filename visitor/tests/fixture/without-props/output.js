@@ -1,2 +1,3 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
-_createVNode("a", null, [_createTextVNode("a")]);
+const _hoisted_1 = _createVNode("a", null, [_createTextVNode("a")], -1);
+_hoisted_1;